@@ -0,0 +1,188 @@
+use anyhow::Result;
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Locations searched for an ownership file, in the same order GitHub uses
+const CODEOWNERS_LOCATIONS: &[&str] = &["CODEOWNERS", ".github/CODEOWNERS", "docs/CODEOWNERS"];
+
+#[derive(Debug, Clone)]
+pub struct OwnerRule {
+    pub pattern: String,
+    pub owners: Vec<String>,
+    pub line: usize,
+    matcher: Regex,
+}
+
+impl OwnerRule {
+    pub fn matches(&self, path: &str) -> bool {
+        self.matcher.is_match(path)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    pub rules: Vec<OwnerRule>,
+}
+
+impl CodeOwners {
+    /// Find and parse the ownership file for a project, if there is one
+    pub fn discover(project_path: &Path) -> Result<Option<(PathBuf, Self)>> {
+        for location in CODEOWNERS_LOCATIONS {
+            let path = project_path.join(location);
+            if path.is_file() {
+                let content = fs::read_to_string(&path)?;
+                return Ok(Some((path, Self::parse(&content)?)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut rules = Vec::new();
+
+        for (index, raw_line) in content.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+            let pattern = match parts.next() {
+                Some(pattern) => pattern.to_string(),
+                None => continue,
+            };
+            let owners: Vec<String> = parts
+                .take_while(|part| !part.starts_with('#'))
+                .map(|part| part.to_string())
+                .collect();
+
+            rules.push(OwnerRule {
+                matcher: pattern_to_regex(&pattern)?,
+                pattern,
+                owners,
+                line: index + 1,
+            });
+        }
+
+        Ok(Self { rules })
+    }
+
+    /// Resolve the rule owning a path. The last matching rule wins, as in CODEOWNERS.
+    pub fn owner_rule(&self, path: &str) -> Option<&OwnerRule> {
+        let normalized = path.trim_start_matches("./").trim_start_matches('/');
+        self.rules.iter().rev().find(|rule| rule.matches(normalized))
+    }
+}
+
+// Translate a gitignore-style CODEOWNERS pattern into an anchored regex
+fn pattern_to_regex(pattern: &str) -> Result<Regex> {
+    let trimmed = pattern.trim_end_matches('/');
+    // A slash anywhere but the end anchors the pattern to the repository root
+    let anchored = trimmed.contains('/');
+    let body = trimmed.trim_start_matches('/');
+    // Only a pattern naming a directory owns what is beneath it; `docs/*` or `*.md`
+    // match the entries themselves, so `docs/*` does not reach into docs/guides/
+    let last_segment = body.rsplit('/').next().unwrap_or(body);
+    let recursive = pattern.ends_with('/') || last_segment == "**" || !last_segment.contains(['*', '?']);
+
+    let mut expr = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let chars: Vec<char> = body.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                if chars.get(i + 2) == Some(&'/') {
+                    expr.push_str("(?:.*/)?");
+                    i += 3;
+                } else {
+                    expr.push_str(".*");
+                    i += 2;
+                }
+            }
+            '*' => {
+                expr.push_str("[^/]*");
+                i += 1;
+            }
+            '?' => {
+                expr.push_str("[^/]");
+                i += 1;
+            }
+            c => {
+                expr.push_str(&regex::escape(&c.to_string()));
+                i += 1;
+            }
+        }
+    }
+
+    if recursive {
+        expr.push_str("(?:/.*)?");
+    }
+    expr.push('$');
+
+    Ok(Regex::new(&expr)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    impl CodeOwners {
+        fn owners_for(&self, path: &str) -> Vec<String> {
+            self.owner_rule(path)
+                .map(|rule| rule.owners.clone())
+                .unwrap_or_default()
+        }
+    }
+
+    const FIXTURE: &str = "\
+# Default owners
+*                   @synapse/core
+*.md                @synapse/docs
+/src/               @synapse/frontend
+/src/api/           @synapse/backend @alice
+/src/api/**/auth.ts @synapse/security
+";
+
+    #[test]
+    fn test_nested_path_resolves_to_most_specific_rule() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join(".github")).unwrap();
+        fs::write(temp_dir.path().join(".github/CODEOWNERS"), FIXTURE).unwrap();
+
+        let (path, owners) = CodeOwners::discover(temp_dir.path()).unwrap().unwrap();
+        assert!(path.ends_with(".github/CODEOWNERS"));
+        assert_eq!(owners.rules.len(), 5);
+
+        assert_eq!(owners.owners_for("src/api/v1/auth.ts"), vec!["@synapse/security"]);
+        assert_eq!(owners.owners_for("src/api/users.ts"), vec!["@synapse/backend", "@alice"]);
+        assert_eq!(owners.owners_for("src/components/Button.tsx"), vec!["@synapse/frontend"]);
+        assert_eq!(owners.owners_for("docs/guide.md"), vec!["@synapse/docs"]);
+        assert_eq!(owners.owners_for("package.json"), vec!["@synapse/core"]);
+    }
+
+    #[test]
+    fn test_unanchored_pattern_matches_anywhere() {
+        let owners = CodeOwners::parse("build/ @synapse/infra\n").unwrap();
+        assert_eq!(owners.owners_for("packages/cli/build/output.js"), vec!["@synapse/infra"]);
+
+        let owners = CodeOwners::parse("/build/ @synapse/infra\n").unwrap();
+        assert!(owners.owners_for("packages/cli/build/output.js").is_empty());
+        assert_eq!(owners.owners_for("build/output.js"), vec!["@synapse/infra"]);
+    }
+
+    #[test]
+    fn test_wildcard_pattern_matches_direct_children_only() {
+        let owners = CodeOwners::parse("docs/* @synapse/docs\n").unwrap();
+        assert_eq!(owners.owners_for("docs/getting-started.md"), vec!["@synapse/docs"]);
+        assert!(owners.owners_for("docs/guides/deploy.md").is_empty());
+
+        // Directory patterns, with or without a trailing slash, own everything beneath them
+        let owners = CodeOwners::parse("/build/logs @synapse/infra\ndocs/** @synapse/docs\n").unwrap();
+        assert_eq!(owners.owners_for("build/logs/2024/app.log"), vec!["@synapse/infra"]);
+        assert_eq!(owners.owners_for("docs/guides/deploy.md"), vec!["@synapse/docs"]);
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+use synapse_compiler::{CompilerConfig, SynapseCompiler};
+use walkdir::WalkDir;
+
+const SOURCE_EXTENSIONS: &[&str] = &["ts", "tsx"];
+
+#[derive(Debug)]
+pub struct CompiledFile {
+    pub source_path: PathBuf,
+    pub output_path: PathBuf,
+    pub success: bool,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Compile a file, or every TypeScript file under a directory, writing the
+/// results into `output_dir` with the same relative layout as the sources.
+/// Shared by `synapse build` and `synapse rust compile`.
+pub fn compile_path(target: &Path, output_dir: &Path, config: CompilerConfig) -> Result<Vec<CompiledFile>> {
+    if !target.exists() {
+        return Err(anyhow::anyhow!("Compile target not found: {}", target.display()));
+    }
+
+    let (base, sources) = if target.is_dir() {
+        (target.to_path_buf(), find_sources(target)?)
+    } else {
+        let base = target.parent().map(Path::to_path_buf).unwrap_or_default();
+        (base, vec![target.to_path_buf()])
+    };
+
+    compile_sources(&base, &sources, output_dir, config)
+}
+
+/// Compile `sources` into `output_dir`, laid out relative to `base`
+pub fn compile_sources(base: &Path, sources: &[PathBuf], output_dir: &Path, config: CompilerConfig) -> Result<Vec<CompiledFile>> {
+    let compiler = SynapseCompiler::new(config)?;
+    let mut results = Vec::new();
+
+    for source in sources {
+        let relative = source.strip_prefix(base).unwrap_or(source);
+        let output_path = output_dir.join(relative).with_extension("js");
+        let compilation = compiler.compile_file(source)?;
+
+        if compilation.success {
+            if let Some(parent) = output_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&output_path, &compilation.output)?;
+
+            if let Some(source_map) = &compilation.source_map {
+                fs::write(output_path.with_extension("js.map"), source_map)?;
+            }
+        }
+
+        results.push(CompiledFile {
+            source_path: source.clone(),
+            output_path,
+            success: compilation.success,
+            errors: compilation.errors,
+            warnings: compilation.warnings,
+        });
+    }
+
+    Ok(results)
+}
+
+pub fn find_sources(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut sources = Vec::new();
+
+    for entry in WalkDir::new(dir).sort_by_file_name() {
+        let entry = entry?;
+        let is_source = entry
+            .path()
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext));
+
+        if entry.file_type().is_file() && is_source {
+            sources.push(entry.into_path());
+        }
+    }
+
+    Ok(sources)
+}
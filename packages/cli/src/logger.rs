@@ -0,0 +1,102 @@
+use console::style;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    Text,
+    Json,
+    Quiet,
+}
+
+impl OutputMode {
+    pub fn from_flags(json: bool, quiet: bool) -> Self {
+        if json {
+            OutputMode::Json
+        } else if quiet {
+            OutputMode::Quiet
+        } else {
+            OutputMode::Text
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+static MODE: AtomicU8 = AtomicU8::new(0);
+
+// Tests run in parallel, so each test thread gets its own capture buffer.
+// The mode itself is the same process-wide setting in tests and in production.
+#[cfg(test)]
+thread_local! {
+    static CAPTURED: RefCell<Option<Vec<(Stream, String)>>> = const { RefCell::new(None) };
+}
+
+pub fn set_mode(mode: OutputMode) {
+    let value = match mode {
+        OutputMode::Text => 0,
+        OutputMode::Json => 1,
+        OutputMode::Quiet => 2,
+    };
+    MODE.store(value, Ordering::Relaxed);
+}
+
+pub fn mode() -> OutputMode {
+    match MODE.load(Ordering::Relaxed) {
+        1 => OutputMode::Json,
+        2 => OutputMode::Quiet,
+        _ => OutputMode::Text,
+    }
+}
+
+/// Report that a subsystem is ready. `component` is the machine-readable
+/// name used in JSON mode; `message` is what humans see in text mode.
+/// JSON events go to stderr so stdout stays free for a command's own JSON report.
+pub fn init(component: &str, message: &str) {
+    match mode() {
+        OutputMode::Text => emit(Stream::Stdout, style(message).green().to_string()),
+        OutputMode::Json => emit(
+            Stream::Stderr,
+            serde_json::json!({ "event": "init", "component": component }).to_string(),
+        ),
+        OutputMode::Quiet => {}
+    }
+}
+
+fn emit(stream: Stream, line: String) {
+    #[cfg(test)]
+    {
+        let captured = CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+            Some(lines) => {
+                lines.push((stream, line.clone()));
+                true
+            }
+            None => false,
+        });
+        if captured {
+            return;
+        }
+    }
+
+    match stream {
+        Stream::Stdout => println!("{}", line),
+        Stream::Stderr => eprintln!("{}", line),
+    }
+}
+
+/// Collect emitted lines on this thread instead of printing them
+#[cfg(test)]
+pub fn start_capture() {
+    CAPTURED.with(|captured| *captured.borrow_mut() = Some(Vec::new()));
+}
+
+#[cfg(test)]
+pub fn take_captured() -> Vec<(Stream, String)> {
+    CAPTURED.with(|captured| captured.borrow_mut().take().unwrap_or_default())
+}
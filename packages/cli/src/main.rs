@@ -1,9 +1,13 @@
+mod codeowners;
+mod compiler;
+mod logger;
 mod types;
 use types::*;
+use codeowners::CodeOwners;
 
 use clap::{Parser, Subcommand};
-use std::path::PathBuf;
-use anyhow::Result;
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result};
 use console::style;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -18,6 +22,7 @@ use indicatif::{ProgressBar, ProgressStyle};
 use dialoguer::{Confirm, Input, Select};
 // use reqwest::Client;  // Commented out to avoid unused import
 use pprof::ProfilerGuard;
+use synapse_compiler::{CompilerConfig, SynapseCompiler};
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
 static ALLOCATOR: dhat::Alloc = dhat::Alloc;
@@ -26,31 +31,47 @@ static ALLOCATOR: dhat::Alloc = dhat::Alloc;
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    logger::set_mode(logger::OutputMode::from_flags(cli.json, cli.quiet));
 
     match cli.command {
         Commands::Init { name, template, yes } => {
             init_project(&name, template, yes).await?;
         }
-        Commands::Dev { port, open } => {
-            start_dev_server(port, open).await?;
+        Commands::Dev { port, open, no_open, https, no_https } => {
+            start_dev_server(port, flag_override(open, no_open), flag_override(https, no_https)).await?;
         }
-        Commands::Build { output, minify } => {
-            build_project(&output, minify).await?;
+        Commands::Build { output, minify, no_minify, incremental, no_incremental } => {
+            build_project(output, flag_override(minify, no_minify), flag_override(incremental, no_incremental)).await?;
         }
-        Commands::Test { pattern, watch } => {
-            run_tests(pattern, watch).await?;
+        Commands::Test { pattern, watch, no_watch, coverage, no_coverage, shard } => {
+            let shard = shard.as_deref().map(TestShard::parse).transpose()?;
+            run_tests(pattern, flag_override(watch, no_watch), flag_override(coverage, no_coverage), cli.json, shard).await?;
         }
-        Commands::Lint { fix } => {
-            lint_code(fix).await?;
+        Commands::Lint { fix, no_fix, watch } => {
+            let fix = flag_override(fix, no_fix);
+            if watch {
+                watch_and_rerun(move || lint_code(fix)).await?;
+            } else {
+                lint_code(fix).await?;
+            }
         }
-        Commands::Format { check } => {
-            format_code(check).await?;
+        Commands::Format { check, no_check, watch } => {
+            let check = flag_override(check, no_check);
+            if watch {
+                watch_and_rerun(move || format_code(check)).await?;
+            } else {
+                format_code(check).await?;
+            }
         }
-        Commands::Generate { type_, name } => {
-            generate_code(&type_, &name).await?;
+        Commands::Generate { type_, name, list } => {
+            if list {
+                list_generators().await?;
+            } else if let (Some(type_), Some(name)) = (type_, name) {
+                generate_code(&type_, &name).await?;
+            }
         }
-        Commands::Plugin { action, name } => {
-            handle_plugin_command(&action, name.as_deref()).await?;
+        Commands::Plugin { action, name, sync } => {
+            handle_plugin_command(&action, name.as_deref(), sync).await?;
         }
         Commands::Template { action, name } => {
             handle_template_command(&action, name.as_deref()).await?;
@@ -67,14 +88,14 @@ async fn main() -> Result<()> {
         Commands::HotReload { action, options } => {
             handle_hot_reload_command(&action, options.as_deref()).await?;
         }
-        Commands::Deploy { action, target } => {
-            handle_deploy_command(&action, target.as_deref()).await?;
+        Commands::Deploy { action, target, force } => {
+            handle_deploy_command(&action, target.as_deref(), force).await?;
         }
         Commands::Monitor { action, options } => {
             handle_monitor_command(&action, options.as_deref()).await?;
         }
         Commands::Profile { action, options } => {
-            handle_profile_command(&action, options.as_deref()).await?;
+            handle_profile_command(&action, options.as_deref(), cli.json).await?;
         }
         Commands::Security { action, options } => {
             handle_security_command(&action, options.as_deref()).await?;
@@ -113,10 +134,11 @@ async fn main() -> Result<()> {
 async fn main() -> Result<()> {
     let _profiler = dhat::Profiler::new_heap();
     let cli = Cli::parse();
+    logger::set_mode(logger::OutputMode::from_flags(cli.json, cli.quiet));
 
     match cli.command {
         Commands::Profile { action, options } => {
-            handle_profile_command(&action, options.as_deref()).await?;
+            handle_profile_command(&action, options.as_deref(), cli.json).await?;
         }
         _ => {
             println!("{}", style("Only the 'profile memory' command is available when using memory profiling.").yellow());
@@ -169,8 +191,14 @@ async fn init_project(name: &str, template: Option<String>, yes: bool) -> Result
     
     println!("{}", style(format!("Template: {}", selected_template)).bold());
     
-    // Create project structure
-    create_project_structure(&project_path, name, &selected_template).await?;
+    // Create project structure, preferring a locally cached template
+    let from_cache = match template_cache_dir() {
+        Ok(cache_root) => scaffold_from_cached_template(&cache_root, &selected_template, &project_path, name).await?,
+        Err(_) => false,
+    };
+    if !from_cache {
+        create_project_structure(&project_path, name, &selected_template).await?;
+    }
     
     // Create project configuration
     let config = ProjectConfig {
@@ -180,6 +208,9 @@ async fn init_project(name: &str, template: Option<String>, yes: bool) -> Result
         features: vec!["typescript".to_string(), "testing".to_string()],
         created_at: Utc::now(),
         last_modified: Utc::now(),
+        defaults: HashMap::new(),
+        testing: TestingSettings::default(),
+        ai: AiSettings::default(),
     };
     
     save_project_config(&project_path, &config)?;
@@ -543,10 +574,99 @@ synapse lint
         serde_json::to_string_pretty(&tsconfig)?
     ).await?;
     
+    write_project_dotfiles(project_path, template).await?;
+    
     Ok(())
 }
 
-async fn start_dev_server(port: u16, open: bool) -> Result<()> {
+// Create .gitignore and .editorconfig, keeping any the user or template already has
+async fn write_project_dotfiles(project_path: &Path, template: &str) -> Result<()> {
+    let gitignore_path = project_path.join(".gitignore");
+    if !gitignore_path.exists() {
+        tokio::fs::write(gitignore_path, generate_gitignore(template)).await?;
+    }
+    
+    let editorconfig_path = project_path.join(".editorconfig");
+    if !editorconfig_path.exists() {
+        tokio::fs::write(editorconfig_path, EDITORCONFIG).await?;
+    }
+    
+    Ok(())
+}
+
+const EDITORCONFIG: &str = r#"root = true
+
+[*]
+charset = utf-8
+end_of_line = lf
+indent_style = space
+indent_size = 2
+insert_final_newline = true
+trim_trailing_whitespace = true
+
+[*.md]
+trim_trailing_whitespace = false
+
+[Makefile]
+indent_style = tab
+"#;
+
+fn generate_gitignore(template: &str) -> String {
+    let mut entries = vec![
+        "# Dependencies",
+        "node_modules/",
+        "",
+        "# Build output",
+        "dist/",
+        "coverage/",
+        "",
+        "# Synapse",
+        ".synapse-cache/",
+        ".synapse/certs/",
+        "",
+        "# Environment",
+        ".env",
+        ".env.local",
+        "",
+        "# Logs",
+        "*.log",
+        "npm-debug.log*",
+        "",
+        "# Editors and OS",
+        ".vscode/",
+        ".idea/",
+        ".DS_Store",
+    ];
+    
+    // Add template-specific entries
+    match template {
+        "fullstack" => {
+            entries.extend(vec!["", "# Fullstack", "src/client/dist/", "src/server/dist/"]);
+        }
+        "ui-library" => {
+            entries.extend(vec!["", "# Storybook", "storybook-static/"]);
+        }
+        "enterprise" => {
+            entries.extend(vec!["", "# Deployment secrets", "deployments/secrets/", "*.pem", "*.key"]);
+        }
+        _ => {} // default and api templates
+    }
+    
+    let mut gitignore = entries.join("\n");
+    gitignore.push('\n');
+    gitignore
+}
+
+// An explicit `--flag` or `--no-flag`, or None when neither was passed
+fn flag_override(on: bool, off: bool) -> Option<bool> {
+    match (on, off) {
+        (true, _) => Some(true),
+        (_, true) => Some(false),
+        _ => None,
+    }
+}
+
+async fn start_dev_server(port: Option<u16>, open: Option<bool>, https: Option<bool>) -> Result<()> {
     let pb = create_progress_bar("Starting development server...");
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
     
@@ -554,29 +674,42 @@ async fn start_dev_server(port: u16, open: bool) -> Result<()> {
     let project_path = std::env::current_dir()?;
     let config = get_project_config(&project_path)?;
     
+    // Explicit flags win over config defaults, which win over built-in defaults
+    let port = port.or_else(|| config.command_default("dev", "port")).unwrap_or(3000);
+    let open = open.or_else(|| config.command_default("dev", "open")).unwrap_or(false);
+    let https = https.or_else(|| config.command_default("dev", "https")).unwrap_or(false);
+    
     println!("{}", style("🚀 Starting development server...").bold().green());
     
     // Initialize framework components
     pb.set_message("Initializing Runtime Engine...");
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-    println!("{}", style("🏃 Runtime Engine initialized").green());
+    logger::init("runtime", "🏃 Runtime Engine initialized");
     
     pb.set_message("Initializing Router...");
     tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
-    println!("{}", style("🛣️ Router initialized").green());
+    logger::init("router", "🛣️ Router initialized");
     
     pb.set_message("Initializing State Manager...");
     tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
-    println!("{}", style("📊 State Manager initialized").green());
+    logger::init("state", "📊 State Manager initialized");
     
     pb.set_message("Initializing Plugin System...");
     tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
-    println!("{}", style("🔌 Plugin System initialized").green());
+    logger::init("plugins", "🔌 Plugin System initialized");
     
     pb.set_message("Starting Hot Reload...");
     tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
     println!("{}", style("🔄 Hot reload enabled").green());
     
+    // The certificate is prepared ahead of TLS serving, which the dev server does not do yet
+    if https {
+        pb.set_message("Preparing development certificate...");
+        let cert = ensure_dev_certificate(&project_path)?;
+        println!("{}", style(format!("🔐 Prepared development certificate: {}", cert.cert_path.display())).green());
+        println!("{}", style("⚠️ HTTPS serving is not available yet; the server still uses http").yellow());
+    }
+    
     pb.finish_with_message("Development server started");
     
     println!("{}", style(format!("✅ Development server started on http://localhost:{}", port)).bold().green());
@@ -614,42 +747,114 @@ async fn start_dev_server(port: u16, open: bool) -> Result<()> {
     Ok(())
 }
 
-async fn build_project(output: &str, minify: bool) -> Result<()> {
+#[derive(Debug)]
+struct DevCertificate {
+    cert_path: PathBuf,
+    key_path: PathBuf,
+}
+
+// Reuse the cached self-signed localhost certificate, generating it on first use
+fn ensure_dev_certificate(project_path: &Path) -> Result<DevCertificate> {
+    let cert_dir = project_path.join(".synapse").join("certs");
+    let cert = DevCertificate {
+        cert_path: cert_dir.join("localhost.pem"),
+        key_path: cert_dir.join("localhost-key.pem"),
+    };
+    
+    if cert.cert_path.exists() && cert.key_path.exists() {
+        return Ok(cert);
+    }
+    
+    fs::create_dir_all(&cert_dir)?;
+    let mut params = rcgen::CertificateParams::new(vec!["localhost".to_string()]);
+    params.subject_alt_names.push(rcgen::SanType::IpAddress(std::net::Ipv4Addr::LOCALHOST.into()));
+    let generated = rcgen::Certificate::from_params(params)?;
+    
+    fs::write(&cert.cert_path, generated.serialize_pem()?)?;
+    write_private_file(&cert.key_path, &generated.serialize_private_key_pem())?;
+    
+    Ok(cert)
+}
+
+// Write a file only the current user can read, e.g. a private key
+fn write_private_file(path: &Path, contents: &str) -> Result<()> {
+    use std::io::Write;
+    
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    
+    let mut file = options.open(path)?;
+    // The mode only applies to new files, so tighten an existing one too
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+    file.write_all(contents.as_bytes())?;
+    
+    Ok(())
+}
+
+async fn build_project(output: Option<String>, minify: Option<bool>, incremental: Option<bool>) -> Result<()> {
+    // Check dependencies
+    check_dependencies().await?;
+    
+    let project_path = std::env::current_dir()?;
+    build_project_at(&project_path, output, minify, incremental).await
+}
+
+async fn build_project_at(project_path: &Path, output: Option<String>, minify: Option<bool>, incremental: Option<bool>) -> Result<()> {
     let pb = create_progress_bar("Building project...");
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
     
     let start_time = std::time::Instant::now();
-    let project_path = std::env::current_dir()?;
+    let project_path = project_path.to_path_buf();
     let config = get_project_config(&project_path)?;
     
+    // Explicit flags win over config defaults, which win over built-in defaults
+    let output = output
+        .or_else(|| config.command_default("build", "output"))
+        .unwrap_or_else(|| "dist".to_string());
+    let output = output.as_str();
+    let minify = minify.or_else(|| config.command_default("build", "minify")).unwrap_or(false);
+    let incremental = incremental.or_else(|| config.command_default("build", "incremental")).unwrap_or(false);
+    
     println!("{}", style("🔨 Building project...").bold().green());
     println!("{}", style(format!("📦 Project: {}", config.name)).bold());
     
-    // Check dependencies
-    pb.set_message("Checking dependencies...");
-    check_dependencies().await?;
-    
-    // Initialize compiler
-    pb.set_message("Initializing compiler...");
-    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-    println!("{}", style("🔨 Compiler initialized").green());
-    
-    // Find TypeScript files
-    pb.set_message("Scanning source files...");
-    let ts_files = find_files_by_extension(&project_path.join("src"), "ts")?;
-    let tsx_files = find_files_by_extension(&project_path.join("src"), "tsx")?;
-    let total_files = ts_files.len() + tsx_files.len();
-    println!("{}", style(format!("📄 Found {} TypeScript files", total_files)).green());
-    
-    // Compile TypeScript
+    // Compile TypeScript through the same path as `synapse rust compile`
     pb.set_message("Compiling TypeScript...");
-    tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+    let compiler_config = CompilerConfig {
+        minify,
+        ..CompilerConfig::default()
+    };
+    let (results, reused_files) = if incremental {
+        compile_incremental(&project_path, &project_path.join(output), compiler_config)?
+    } else {
+        (compiler::compile_path(&project_path.join("src"), &project_path.join(output), compiler_config)?, 0)
+    };
+    let total_files = results.len() + reused_files;
+    println!("{}", style(format!("📄 Found {} TypeScript files", total_files)).green());
+    if incremental {
+        println!("{}", style(format!("♻️ Reused {} unchanged files", reused_files)).green());
+    }
+
+    let failed: Vec<_> = results.iter().filter(|result| !result.success).collect();
+    if !failed.is_empty() {
+        pb.finish_and_clear();
+        for result in &failed {
+            println!("  {} {}", style("❌").red(), result.source_path.display());
+            for error in &result.errors {
+                println!("    {}", style(format!("Error: {}", error)).red());
+            }
+        }
+        return Err(anyhow::anyhow!("{} of {} files failed to compile", failed.len(), total_files));
+    }
     println!("{}", style("✅ Compilation completed").green());
     
     // Run linting
     pb.set_message("Running linter...");
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-    println!("{}", style("🔍 Linting System initialized").green());
+    logger::init("linting", "🔍 Linting System initialized");
     println!("{}", style("✅ Linting completed").green());
     
     // Minify if requested
@@ -677,7 +882,8 @@ async fn build_project(output: &str, minify: bool) -> Result<()> {
         "template": config.template,
         "build_time": start_time.elapsed().as_secs_f64(),
         "minified": minify,
-        "files_compiled": total_files,
+        "files_compiled": results.len(),
+        "files_reused": reused_files,
         "timestamp": Utc::now()
     });
     
@@ -692,11 +898,121 @@ async fn build_project(output: &str, minify: bool) -> Result<()> {
     println!("{}", style("✅ Build completed successfully").bold().green());
     println!("{}", style(format!("📁 Output directory: {}", output)).bold());
     println!("{}", style(format!("⏱️ Build time: {}", format_duration(build_time.as_secs_f64()))).green());
-    println!("{}", style(format!("📄 Files compiled: {}", total_files)).green());
+    println!("{}", style(format!("📄 Files compiled: {}", results.len())).green());
+    
+    Ok(())
+}
+
+fn build_cache_dir(project_path: &Path) -> PathBuf {
+    project_path.join(".synapse-cache")
+}
+
+fn load_build_manifest(project_path: &Path) -> BuildManifest {
+    fs::read_to_string(build_cache_dir(project_path).join("build-manifest.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_build_manifest(project_path: &Path, manifest: &BuildManifest) -> Result<()> {
+    let cache_dir = build_cache_dir(project_path);
+    fs::create_dir_all(&cache_dir)?;
+    fs::write(cache_dir.join("build-manifest.json"), serde_json::to_string_pretty(manifest)?)?;
+    Ok(())
+}
+
+fn hash_compiler_config(config: &CompilerConfig) -> Result<String> {
+    use sha2::{Sha256, Digest};
+    let mut hasher = Sha256::new();
+    hasher.update(serde_json::to_vec(config)?);
+    Ok(hex::encode(hasher.finalize()))
+}
+
+// Drop the output of a source that no longer compiles, so the last good build
+// of it is neither reused from the cache nor left behind in the output directory
+fn discard_stale_output(cached_output_dir: &Path, output_dir: &Path, relative_output: &Path) -> Result<()> {
+    for dir in [cached_output_dir, output_dir] {
+        let output = dir.join(relative_output);
+        for path in [output.with_extension("js.map"), output] {
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+        }
+    }
     
     Ok(())
 }
 
+// Recompile only sources whose hash changed since the last build. Outputs are
+// kept under .synapse-cache so unchanged files can be restored into a clean
+// output directory. Returns the compiled files and how many were reused.
+fn compile_incremental(project_path: &Path, output_dir: &Path, config: CompilerConfig) -> Result<(Vec<compiler::CompiledFile>, usize)> {
+    let source_dir = project_path.join("src");
+    let cached_output_dir = build_cache_dir(project_path).join("build");
+    let previous = load_build_manifest(project_path);
+    let config_hash = hash_compiler_config(&config)?;
+
+    // Different compiler settings invalidate every cached output
+    let cache_valid = previous.config_hash == config_hash;
+
+    let mut manifest = BuildManifest {
+        config_hash,
+        files: HashMap::new(),
+    };
+    let mut changed = Vec::new();
+    let sources = compiler::find_sources(&source_dir)?;
+
+    for source in &sources {
+        let relative = source.strip_prefix(&source_dir).unwrap_or(source);
+        let key = relative.to_string_lossy().replace('\\', "/");
+        let hash = calculate_file_hash(source)?;
+        let cached_output = cached_output_dir.join(relative).with_extension("js");
+
+        if !cache_valid || previous.files.get(&key) != Some(&hash) || !cached_output.exists() {
+            changed.push(source.clone());
+        }
+        manifest.files.insert(key, hash);
+    }
+
+    let mut results = compiler::compile_sources(&source_dir, &changed, &cached_output_dir, config)?;
+    for result in results.iter().filter(|result| !result.success) {
+        let relative = result.source_path.strip_prefix(&source_dir).unwrap_or(&result.source_path);
+        manifest.files.remove(&relative.to_string_lossy().replace('\\', "/"));
+        discard_stale_output(&cached_output_dir, output_dir, &relative.with_extension("js"))?;
+    }
+
+    // Copy compiled and reused outputs alike from the cache into the output directory
+    for source in &sources {
+        let relative = source.strip_prefix(&source_dir).unwrap_or(source).with_extension("js");
+        let cached_output = cached_output_dir.join(&relative);
+        if !cached_output.exists() {
+            continue;
+        }
+
+        let output_path = output_dir.join(&relative);
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(&cached_output, &output_path)?;
+
+        let cached_map = cached_output.with_extension("js.map");
+        if cached_map.exists() {
+            fs::copy(&cached_map, output_path.with_extension("js.map"))?;
+        }
+    }
+
+    save_build_manifest(project_path, &manifest)?;
+
+    // Report where each output ended up rather than its cached location
+    for result in &mut results {
+        if let Ok(relative) = result.output_path.strip_prefix(&cached_output_dir) {
+            result.output_path = output_dir.join(relative);
+        }
+    }
+
+    Ok((results, sources.len() - changed.len()))
+}
+
 async fn copy_directory(src: &PathBuf, dst: &PathBuf) -> Result<()> {
     if !src.exists() {
         return Ok(());
@@ -718,26 +1034,39 @@ async fn copy_directory(src: &PathBuf, dst: &PathBuf) -> Result<()> {
     Ok(())
 }
 
-async fn run_tests(pattern: Option<String>, watch: bool) -> Result<()> {
+async fn run_tests(pattern: Option<String>, watch: Option<bool>, coverage: Option<bool>, json: bool, shard: Option<TestShard>) -> Result<()> {
     let pb = create_progress_bar("Running tests...");
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
     
     let start_time = std::time::Instant::now();
     let project_path = std::env::current_dir()?;
     let config = get_project_config(&project_path)?;
+    let (watch, coverage) = resolve_test_flags(&config, watch, coverage);
+    // The JSON coverage summary owns stdout, so progress lines go to stderr
+    let json_output = coverage && json;
     
-    println!("{}", style("🧪 Running tests...").bold().green());
-    println!("{}", style(format!("📦 Project: {}", config.name)).bold());
+    print_status(json_output, style("🧪 Running tests...").bold().green());
+    print_status(json_output, style(format!("📦 Project: {}", config.name)).bold());
     
     // Initialize testing framework
     pb.set_message("Initializing testing framework...");
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-    println!("{}", style("🧪 Testing Framework initialized").green());
+    logger::init("testing", "🧪 Testing Framework initialized");
+    
+    if coverage {
+        pb.finish_and_clear();
+        return run_coverage(&project_path, config.testing.coverage_threshold, pattern.as_deref(), json, shard);
+    }
     
     // Find test files
     pb.set_message("Scanning for test files...");
-    let test_files = find_files_by_extension(&project_path.join("tests"), "ts")?;
-    let test_js_files = find_files_by_extension(&project_path.join("tests"), "js")?;
+    let mut test_files = find_files_by_extension(&project_path.join("tests"), "ts")?;
+    let mut test_js_files = find_files_by_extension(&project_path.join("tests"), "js")?;
+    if let Some(shard) = shard {
+        test_files.retain(|file| shard.contains(&project_path, file));
+        test_js_files.retain(|file| shard.contains(&project_path, file));
+        println!("{}", style(format!("🧩 Running shard {}/{}", shard.index, shard.total)).green());
+    }
     let total_test_files = test_files.len() + test_js_files.len();
     
     if total_test_files == 0 {
@@ -784,6 +1113,227 @@ async fn run_tests(pattern: Option<String>, watch: bool) -> Result<()> {
     Ok(())
 }
 
+// Explicit flags win over config defaults, which win over built-in defaults
+fn resolve_test_flags(config: &ProjectConfig, watch: Option<bool>, coverage: Option<bool>) -> (bool, bool) {
+    let watch = watch.or_else(|| config.command_default("test", "watch")).unwrap_or(false);
+    let coverage = coverage.or_else(|| config.command_default("test", "coverage")).unwrap_or(false);
+    (watch, coverage)
+}
+
+// Run the suite under `node --test` with coverage and enforce the threshold.
+// Node is always given an explicit file list, so the files counted are the files run.
+fn run_coverage(project_path: &Path, threshold: f64, pattern: Option<&str>, json: bool, shard: Option<TestShard>) -> Result<()> {
+    let mut test_files = find_node_test_files(project_path)?;
+    if let Some(shard) = shard {
+        test_files.retain(|file| shard.contains(project_path, file));
+        print_status(json, style(format!("🧩 Running shard {}/{}", shard.index, shard.total)).green());
+    }
+    
+    if test_files.is_empty() {
+        print_status(json, style("⚠️ No node-runnable test files found").yellow());
+        print_status(json, style("Coverage runs JavaScript tests with node: name them *.test.js, *.test.mjs or *.test.cjs").yellow());
+        return Ok(());
+    }
+    
+    print_status(json, style(format!("📄 Found {} test files", test_files.len())).green());
+    if let Some(pattern) = pattern {
+        print_status(json, style(format!("🔍 Test pattern: {}", pattern)).green());
+    }
+    
+    let mut command = Command::new("node");
+    command.args(["--test", "--experimental-test-coverage"]);
+    if let Some(pattern) = pattern {
+        command.args(["--test-name-pattern", pattern]);
+    }
+    let output = command
+        .args(&test_files)
+        .current_dir(project_path)
+        .stderr(std::process::Stdio::inherit())
+        .output()?;
+    
+    let report = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        // Node's report names the failing tests and their assertions
+        print_status(json, report.trim_end());
+    }
+    
+    let summary = match parse_coverage_summary(&report) {
+        Some(summary) => summary,
+        None if !output.status.success() => return Err(anyhow::anyhow!("Tests failed")),
+        None => return Err(anyhow::anyhow!("No coverage report found in test output")),
+    };
+    
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else {
+        display_coverage_summary(&summary, threshold);
+    }
+    
+    if !output.status.success() {
+        return Err(anyhow::anyhow!("Tests failed"));
+    }
+    if !summary.meets_threshold(threshold) {
+        return Err(anyhow::anyhow!(
+            "Line coverage {:.2}% is below the {:.2}% threshold",
+            summary.total.lines, threshold
+        ));
+    }
+    
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TestShard {
+    /// 1-based shard to run
+    index: usize,
+    total: usize,
+}
+
+impl TestShard {
+    fn parse(value: &str) -> Result<Self> {
+        let (index, total) = value
+            .split_once('/')
+            .ok_or_else(|| anyhow::anyhow!("Invalid shard '{}': expected <index>/<total>", value))?;
+        let index: usize = index.trim().parse().map_err(|_| anyhow::anyhow!("Invalid shard index: {}", index))?;
+        let total: usize = total.trim().parse().map_err(|_| anyhow::anyhow!("Invalid shard total: {}", total))?;
+        
+        if index < 1 || index > total {
+            return Err(anyhow::anyhow!("Shard index must be between 1 and {}, got {}", total, index));
+        }
+        
+        Ok(Self { index, total })
+    }
+    
+    // Bucket by a hash of the project-relative path so every runner agrees
+    // on the partition regardless of checkout location or discovery order
+    fn contains(&self, project_path: &Path, file: &Path) -> bool {
+        use sha2::{Sha256, Digest};
+        let relative = file.strip_prefix(project_path).unwrap_or(file);
+        let digest = Sha256::digest(relative.to_string_lossy().replace('\\', "/").as_bytes());
+        let mut prefix = [0u8; 8];
+        prefix.copy_from_slice(&digest[..8]);
+        let bucket = u64::from_be_bytes(prefix) % self.total as u64;
+        bucket as usize == self.index - 1
+    }
+}
+
+// Files `node --test` picks up on its own: *.test.js, *-test.js, *_test.js, test-*.js,
+// test.js and anything under a `test` directory, with .js/.cjs/.mjs, outside node_modules
+fn is_node_test_file(relative: &Path) -> bool {
+    let is_script = relative
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext, "js" | "cjs" | "mjs"));
+    if !is_script {
+        return false;
+    }
+    
+    let stem = relative.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+    let named_as_test = stem == "test"
+        || stem.starts_with("test-")
+        || [".test", "-test", "_test"].iter().any(|suffix| stem.ends_with(suffix));
+    let in_test_dir = relative
+        .parent()
+        .is_some_and(|parent| parent.components().any(|component| component.as_os_str() == "test"));
+    
+    named_as_test || in_test_dir
+}
+
+fn find_node_test_files(project_path: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let walker = WalkDir::new(project_path)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != "node_modules");
+    
+    for entry in walker {
+        let entry = entry?;
+        let relative = entry.path().strip_prefix(project_path).unwrap_or(entry.path());
+        if entry.file_type().is_file() && is_node_test_file(relative) {
+            files.push(entry.into_path());
+        }
+    }
+    
+    Ok(files)
+}
+
+fn print_status(to_stderr: bool, line: impl std::fmt::Display) {
+    if to_stderr {
+        eprintln!("{}", line);
+    } else {
+        println!("{}", line);
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct CoverageEntry {
+    file: String,
+    lines: f64,
+    branches: f64,
+    functions: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct CoverageSummary {
+    files: Vec<CoverageEntry>,
+    total: CoverageEntry,
+}
+
+impl CoverageSummary {
+    fn meets_threshold(&self, threshold: f64) -> bool {
+        self.total.lines >= threshold
+    }
+}
+
+// Parse the table printed by `node --test --experimental-test-coverage`
+fn parse_coverage_summary(output: &str) -> Option<CoverageSummary> {
+    let mut files = Vec::new();
+    let mut total = None;
+    
+    for line in output.lines() {
+        let line = line.trim_start_matches(['#', 'ℹ', ' ']);
+        let columns: Vec<&str> = line.split('|').map(str::trim).collect();
+        if columns.len() < 4 {
+            continue;
+        }
+        
+        let percentages: Option<Vec<f64>> = columns[1..4].iter().map(|c| c.parse().ok()).collect();
+        let Some(percentages) = percentages else {
+            continue; // header row
+        };
+        
+        let entry = CoverageEntry {
+            file: columns[0].to_string(),
+            lines: percentages[0],
+            branches: percentages[1],
+            functions: percentages[2],
+        };
+        
+        if entry.file == "all files" {
+            total = Some(entry);
+        } else {
+            files.push(entry);
+        }
+    }
+    
+    total.map(|total| CoverageSummary { files, total })
+}
+
+fn display_coverage_summary(summary: &CoverageSummary, threshold: f64) {
+    println!();
+    println!("{}", style("Coverage Summary:").bold());
+    println!("  {:<40} {:>8} {:>10} {:>8}", "File", "Lines %", "Branches %", "Funcs %");
+    
+    for entry in summary.files.iter().chain(std::iter::once(&summary.total)) {
+        let lines = format!("{:>8.2}", entry.lines);
+        let lines = if entry.lines >= threshold { style(lines).green() } else { style(lines).red() };
+        println!("  {:<40} {} {:>10.2} {:>8.2}", entry.file, lines, entry.branches, entry.functions);
+    }
+    
+    println!();
+    println!("{}", style(format!("Threshold: {:.2}% lines", threshold)).bold());
+}
+
 #[derive(Debug)]
 struct TestResult {
     name: String,
@@ -876,13 +1426,19 @@ fn display_test_results(results: &[TestResult]) {
     }
 }
 
-async fn lint_code(fix: bool) -> Result<()> {
+async fn lint_code(fix: Option<bool>) -> Result<()> {
+    let project_path = std::env::current_dir()?;
+    lint_code_at(&project_path, fix).await
+}
+
+async fn lint_code_at(project_path: &Path, fix: Option<bool>) -> Result<()> {
     let pb = create_progress_bar("Linting code...");
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
     
     let start_time = std::time::Instant::now();
-    let project_path = std::env::current_dir()?;
+    let project_path = project_path.to_path_buf();
     let config = get_project_config(&project_path)?;
+    let fix = fix.or_else(|| config.command_default("lint", "fix")).unwrap_or(false);
     
     println!("{}", style("🔍 Linting code...").bold().green());
     println!("{}", style(format!("📦 Project: {}", config.name)).bold());
@@ -890,7 +1446,7 @@ async fn lint_code(fix: bool) -> Result<()> {
     // Initialize linting system
     pb.set_message("Initializing linting system...");
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-    println!("{}", style("🔍 Linting System initialized").green());
+    logger::init("linting", "🔍 Linting System initialized");
     
     // Find source files
     pb.set_message("Scanning source files...");
@@ -1046,13 +1602,14 @@ fn display_lint_results(issues: &[LintIssue], total_issues: usize, fixable_issue
     println!("{}", style(format!("Found {} issues ({} fixable)", total_issues, fixable_issues)).bold());
 }
 
-async fn format_code(check: bool) -> Result<()> {
+async fn format_code(check: Option<bool>) -> Result<()> {
     let pb = create_progress_bar("Formatting code...");
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
     
     let start_time = std::time::Instant::now();
     let project_path = std::env::current_dir()?;
     let config = get_project_config(&project_path)?;
+    let check = resolve_format_check(&config, check);
     
     println!("{}", style("✨ Formatting code...").bold().green());
     println!("{}", style(format!("📦 Project: {}", config.name)).bold());
@@ -1123,81 +1680,1227 @@ async fn format_code(check: bool) -> Result<()> {
     Ok(())
 }
 
+fn resolve_format_check(config: &ProjectConfig, check: Option<bool>) -> bool {
+    check.or_else(|| config.command_default("format", "check")).unwrap_or(false)
+}
+
 async fn generate_code(type_: &str, name: &str) -> Result<()> {
     println!("{}", style("🔧 Generating code...").bold().green());
     println!("{}", style(format!("Type: {}", type_)).green());
     println!("{}", style(format!("Name: {}", name)).green());
+    
+    let project_path = std::env::current_dir()?;
+    let files = render_generator(&project_path, type_, name)?;
+    
+    for file in &files {
+        let relative = file.strip_prefix(&project_path).unwrap_or(file);
+        println!("  {} {}", style("created").green(), relative.display());
+    }
+    
     println!("{}", style(format!("✅ {} '{}' generated successfully", type_, name)).bold().green());
     
     Ok(())
 }
 
-async fn handle_plugin_command(action: &str, name: Option<&str>) -> Result<()> {
-    println!("{}", style("🔌 Plugin System initialized").green());
-    println!("{}", style(format!("📦 {} plugin: {}", action, name.unwrap_or("list"))).green());
-    println!("{}", style("✅ Plugin operation completed").green());
+async fn list_generators() -> Result<()> {
+    let project_path = std::env::current_dir()?;
     
-    Ok(())
-}
-
-async fn handle_template_command(action: &str, name: Option<&str>) -> Result<()> {
-    println!("{}", style("📋 Template Manager initialized").green());
-    println!("{}", style(format!("📋 {} template: {}", action, name.unwrap_or("list"))).green());
-    println!("{}", style("✅ Template operation completed").green());
+    println!("{}", style("Available generators:").bold());
+    for generator in BUILTIN_GENERATORS {
+        let source = if custom_templates_dir(&project_path).join(generator.type_).is_dir() {
+            "custom"
+        } else {
+            "built-in"
+        };
+        println!("  {:<12} {}", generator.type_, style(source).dim());
+    }
     
-    Ok(())
-}
-
-async fn handle_batch_command(action: &str, config: Option<&str>) -> Result<()> {
-    println!("{}", style("⚡ Batch Processor initialized").green());
-    println!("{}", style(format!("⚡ {} batch: {}", action, config.unwrap_or("default"))).green());
-    println!("{}", style("✅ Batch operation completed").green());
+    for type_ in custom_generator_types(&project_path)? {
+        if !BUILTIN_GENERATORS.iter().any(|generator| generator.type_ == type_) {
+            println!("  {:<12} {}", type_, style("custom").dim());
+        }
+    }
     
     Ok(())
 }
 
-async fn handle_config_command(action: &str, key: Option<&str>) -> Result<()> {
-    println!("{}", style("⚙️ Configuration Manager initialized").green());
-    println!("{}", style(format!("⚙️ {} config: {}", action, key.unwrap_or("all"))).green());
-    println!("{}", style("✅ Configuration operation completed").green());
-    
-    Ok(())
+struct BuiltinGenerator {
+    type_: &'static str,
+    output_dir: &'static str,
+    // (file name, contents) pairs, both subject to placeholder substitution
+    files: &'static [(&'static str, &'static str)],
 }
 
-async fn handle_rust_command(action: &str, target: Option<&str>) -> Result<()> {
-    println!("{}", style("🦀 Rust Compiler initialized").green());
-    println!("{}", style(format!("🦀 {} rust: {}", action, target.unwrap_or("default"))).green());
-    println!("{}", style("✅ Rust operation completed").green());
-    
-    Ok(())
+const BUILTIN_GENERATORS: &[BuiltinGenerator] = &[
+    BuiltinGenerator {
+        type_: "component",
+        output_dir: "src/components",
+        files: &[(
+            "{{Name}}.tsx",
+            "export interface {{Name}}Props {}\n\nexport function {{Name}}(props: {{Name}}Props) {\n    return <div className=\"{{name}}\"></div>;\n}\n",
+        )],
+    },
+    BuiltinGenerator {
+        type_: "page",
+        output_dir: "src/pages",
+        files: &[(
+            "{{Name}}Page.tsx",
+            "export default function {{Name}}Page() {\n    return <main>{{Name}}</main>;\n}\n",
+        )],
+    },
+    BuiltinGenerator {
+        type_: "api",
+        output_dir: "src/api",
+        files: &[(
+            "{{name}}.ts",
+            "export async function get{{Name}}(request: Request): Promise<Response> {\n    return Response.json({ resource: '{{name}}' });\n}\n",
+        )],
+    },
+    BuiltinGenerator {
+        type_: "hook",
+        output_dir: "src/hooks",
+        files: &[(
+            "use{{Name}}.ts",
+            "export function use{{Name}}() {\n    return {};\n}\n",
+        )],
+    },
+    BuiltinGenerator {
+        type_: "service",
+        output_dir: "src/services",
+        files: &[(
+            "{{name}}Service.ts",
+            "export const {{NAME}}_SERVICE = '{{name}}';\n\nexport class {{Name}}Service {}\n",
+        )],
+    },
+];
+
+fn custom_templates_dir(project_path: &Path) -> PathBuf {
+    project_path.join(".synapse").join("templates")
 }
 
-async fn handle_hot_reload_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("🔥 Hot Reload System initialized").green());
-    println!("{}", style(format!("🔥 {} hot-reload: {}", action, options.unwrap_or("default"))).green());
-    println!("{}", style("✅ Hot reload operation completed").green());
+fn custom_generator_types(project_path: &Path) -> Result<Vec<String>> {
+    let templates_dir = custom_templates_dir(project_path);
+    if !templates_dir.is_dir() {
+        return Ok(vec![]);
+    }
     
-    Ok(())
+    let mut types = Vec::new();
+    for entry in fs::read_dir(templates_dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            types.push(entry.file_name().to_string_lossy().to_string());
+        }
+    }
+    types.sort();
+    
+    Ok(types)
 }
 
-async fn handle_deploy_command(action: &str, target: Option<&str>) -> Result<()> {
-    println!("{}", style("🚀 Deployment System initialized").green());
-    println!("{}", style(format!("🚀 {} deploy: {}", action, target.unwrap_or("default"))).green());
-    println!("{}", style("✅ Deployment operation completed").green());
+// Render a generator into the project, preferring `.synapse/templates/<type>/`
+// over the built-in template of the same type.
+fn render_generator(project_path: &Path, type_: &str, name: &str) -> Result<Vec<PathBuf>> {
+    let builtin = BUILTIN_GENERATORS.iter().find(|generator| generator.type_ == type_);
+    let output_dir = match builtin {
+        Some(generator) => project_path.join(generator.output_dir),
+        None => project_path.join("src").join(type_),
+    };
+    let custom_dir = custom_templates_dir(project_path).join(type_);
+    
+    let mut templates = Vec::new();
+    if custom_dir.is_dir() {
+        for entry in WalkDir::new(&custom_dir).sort_by_file_name() {
+            let entry = entry?;
+            if entry.file_type().is_file() {
+                let relative = entry.path().strip_prefix(&custom_dir)?.to_string_lossy().to_string();
+                templates.push((relative, fs::read_to_string(entry.path())?));
+            }
+        }
+    } else if let Some(generator) = builtin {
+        for (file_name, contents) in generator.files {
+            templates.push((file_name.to_string(), contents.to_string()));
+        }
+    } else {
+        return Err(anyhow::anyhow!(
+            "Unknown generator '{}'. Run 'synapse generate --list' to see available generators",
+            type_
+        ));
+    }
+    
+    let outputs: Vec<(PathBuf, String)> = templates.into_iter()
+        .map(|(file_name, contents)| {
+            (output_dir.join(substitute_placeholders(&file_name, name)), substitute_placeholders(&contents, name))
+        })
+        .collect();
+    
+    // Refuse before writing anything, so a clash never leaves a half-generated result
+    if let Some((existing, _)) = outputs.iter().find(|(output_path, _)| output_path.exists()) {
+        return Err(anyhow::anyhow!("{} already exists", existing.display()));
+    }
+    
+    let mut created = Vec::new();
+    for (output_path, contents) in outputs {
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&output_path, contents)?;
+        created.push(output_path);
+    }
+    
+    Ok(created)
+}
+
+// Replace {{name}} (camelCase), {{Name}} (PascalCase) and {{NAME}} (UPPER_SNAKE_CASE)
+fn substitute_placeholders(template: &str, name: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for c in name.chars() {
+        if c == '-' || c == '_' || c.is_whitespace() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+        } else if c.is_uppercase() && current.chars().last().is_some_and(|last| last.is_lowercase()) {
+            words.push(std::mem::take(&mut current));
+            current.push(c);
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    
+    let capitalize = |word: &String| {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+            None => String::new(),
+        }
+    };
+    
+    let pascal: String = words.iter().map(capitalize).collect();
+    let camel = match words.split_first() {
+        Some((first, rest)) => first.to_lowercase() + &rest.iter().map(capitalize).collect::<String>(),
+        None => String::new(),
+    };
+    let upper = words.iter().map(|word| word.to_uppercase()).collect::<Vec<_>>().join("_");
+    
+    template
+        .replace("{{name}}", &camel)
+        .replace("{{Name}}", &pascal)
+        .replace("{{NAME}}", &upper)
+}
+
+async fn handle_plugin_command(action: &str, name: Option<&str>, sync: bool) -> Result<()> {
+    logger::init("plugins", "🔌 Plugin System initialized");
+    
+    let project_path = std::env::current_dir()?;
+    
+    match action {
+        "list" => {
+            let plugins = list_plugins(&project_path).await?;
+            
+            println!("{}", style("Available plugins:").bold());
+            for plugin in &plugins {
+                let status = if plugin.installed {
+                    style("installed").green()
+                } else {
+                    style("available").dim()
+                };
+                println!("  {:<20} {:<8} {:<10} {}", plugin.name, plugin.version, status, plugin.description);
+            }
+        }
+        "install" | "remove" => {
+            let name = name.ok_or_else(|| anyhow::anyhow!("Usage: synapse plugin {} <name>", action))?;
+            
+            if action == "install" {
+                let plugin = install_plugin(&project_path, name).await?;
+                println!("{}", style(format!("✅ Installed {}@{}", plugin.name, plugin.version)).green());
+            } else {
+                remove_plugin(&project_path, name).await?;
+                println!("{}", style(format!("✅ Removed {}", name)).green());
+            }
+            
+            if sync {
+                let pb = create_progress_bar("Running npm install...");
+                pb.enable_steady_tick(std::time::Duration::from_millis(100));
+                let status = Command::new("npm").arg("install").current_dir(&project_path).status()?;
+                pb.finish_and_clear();
+                
+                if !status.success() {
+                    return Err(anyhow::anyhow!("npm install failed"));
+                }
+            } else {
+                println!("{}", style("Run 'npm install' (or pass --sync) to update node_modules").dim());
+            }
+        }
+        _ => {
+            println!("{}", style(format!("Unknown plugin action: {}. Available actions: list, install, remove", action)).yellow());
+        }
+    }
+    
+    Ok(())
+}
+
+fn plugin_state_path(project_path: &Path) -> PathBuf {
+    project_path.join(".synapse").join("plugins.json")
+}
+
+fn load_installed_plugins(project_path: &Path) -> Result<Vec<PluginInfo>> {
+    let state_path = plugin_state_path(project_path);
+    
+    if state_path.exists() {
+        let content = fs::read_to_string(state_path)?;
+        Ok(serde_json::from_str(&content)?)
+    } else {
+        Ok(vec![])
+    }
+}
+
+fn save_installed_plugins(project_path: &Path, plugins: &[PluginInfo]) -> Result<()> {
+    let state_path = plugin_state_path(project_path);
+    if let Some(parent) = state_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    
+    fs::write(state_path, serde_json::to_string_pretty(plugins)?)?;
+    
+    Ok(())
+}
+
+fn read_package_json(project_path: &Path) -> Result<serde_json::Value> {
+    let package_path = project_path.join("package.json");
+    if !package_path.exists() {
+        return Err(anyhow::anyhow!("No package.json found in {}", project_path.display()));
+    }
+    
+    Ok(serde_json::from_str(&fs::read_to_string(package_path)?)?)
+}
+
+fn write_package_json(project_path: &Path, package_json: &serde_json::Value) -> Result<()> {
+    fs::write(
+        project_path.join("package.json"),
+        serde_json::to_string_pretty(package_json)? + "\n",
+    )?;
+    
+    Ok(())
+}
+
+// Merge the registry with the recorded install state. package.json stays
+// authoritative: recorded plugins since removed from it are pruned from the
+// state, while recorded plugins that left the registry are still listed. An
+// unreadable package.json is an error rather than an empty dependency list,
+// so it can never prune the recorded state.
+async fn list_plugins(project_path: &Path) -> Result<Vec<PluginInfo>> {
+    let package_json = read_package_json(project_path)?;
+    let dependencies = package_json.get("dependencies").and_then(|deps| deps.as_object());
+    let dependency_version = |name: &str| {
+        dependencies
+            .and_then(|deps| deps.get(name))
+            .and_then(|version| version.as_str())
+            .map(|version| version.trim_start_matches(['^', '~']).to_string())
+    };
+    
+    let mut installed = load_installed_plugins(project_path)?;
+    let recorded = installed.len();
+    installed.retain(|plugin| dependency_version(&plugin.name).is_some());
+    if installed.len() != recorded {
+        save_installed_plugins(project_path, &installed)?;
+    }
+    
+    let mut plugins = get_available_plugins().await?;
+    for plugin in installed {
+        match plugins.iter_mut().find(|available| available.name == plugin.name) {
+            Some(available) => *available = plugin,
+            None => plugins.push(plugin),
+        }
+    }
+    
+    for plugin in &mut plugins {
+        if let Some(version) = dependency_version(&plugin.name) {
+            plugin.installed = true;
+            plugin.version = version;
+        }
+    }
+    
+    Ok(plugins)
+}
+
+async fn install_plugin(project_path: &Path, name: &str) -> Result<PluginInfo> {
+    let mut plugin = get_available_plugins().await?
+        .into_iter()
+        .find(|plugin| plugin.name == name)
+        .ok_or_else(|| anyhow::anyhow!("Unknown plugin '{}'. Run 'synapse plugin list' to see available plugins", name))?;
+    
+    let mut package_json = read_package_json(project_path)?;
+    let root = package_json.as_object_mut()
+        .ok_or_else(|| anyhow::anyhow!("package.json must contain a JSON object"))?;
+    let dependencies = root.entry("dependencies")
+        .or_insert_with(|| serde_json::json!({}))
+        .as_object_mut()
+        .ok_or_else(|| anyhow::anyhow!("package.json 'dependencies' must be an object"))?;
+    dependencies.insert(plugin.name.clone(), serde_json::json!(format!("^{}", plugin.version)));
+    write_package_json(project_path, &package_json)?;
+    
+    plugin.installed = true;
+    let mut installed = load_installed_plugins(project_path)?;
+    installed.retain(|existing| existing.name != plugin.name);
+    installed.push(plugin.clone());
+    save_installed_plugins(project_path, &installed)?;
+    
+    Ok(plugin)
+}
+
+async fn remove_plugin(project_path: &Path, name: &str) -> Result<()> {
+    let mut package_json = read_package_json(project_path)?;
+    let removed = package_json.get_mut("dependencies")
+        .and_then(|deps| deps.as_object_mut())
+        .and_then(|deps| deps.remove(name))
+        .is_some();
+    
+    if !removed {
+        return Err(anyhow::anyhow!("Plugin '{}' is not installed", name));
+    }
+    write_package_json(project_path, &package_json)?;
+    
+    let mut installed = load_installed_plugins(project_path)?;
+    installed.retain(|existing| existing.name != name);
+    save_installed_plugins(project_path, &installed)?;
+    
+    Ok(())
+}
+
+async fn handle_template_command(action: &str, name: Option<&str>) -> Result<()> {
+    logger::init("templates", "📋 Template Manager initialized");
+    
+    let cache_root = template_cache_dir()?;
+    
+    match action {
+        "list" => {
+            println!("{}", style("Available templates:").bold());
+            for template in get_available_templates().await? {
+                println!("  {:<16} {}", template.name, style(template.description).dim());
+            }
+        }
+        "add" => {
+            let source = name.ok_or_else(|| anyhow::anyhow!("Usage: synapse template add <dir>"))?;
+            let template_name = add_cached_template(&cache_root, Path::new(source)).await?;
+            println!("{}", style(format!("✅ Template '{}' added to {}", template_name, cache_root.display())).green());
+        }
+        "remove" => {
+            let template_name = name.ok_or_else(|| anyhow::anyhow!("Usage: synapse template remove <name>"))?;
+            remove_cached_template(&cache_root, template_name).await?;
+            println!("{}", style(format!("✅ Template '{}' removed", template_name)).green());
+        }
+        _ => {
+            println!("{}", style(format!("Unknown template action: {}. Available actions: list, add, remove", action)).yellow());
+        }
+    }
+    
+    Ok(())
+}
+
+fn template_cache_dir() -> Result<PathBuf> {
+    Ok(get_config_dir()?.join("templates"))
+}
+
+// Copy a local directory into the template cache, named after the directory
+async fn add_cached_template(cache_root: &Path, source: &Path) -> Result<String> {
+    if !source.is_dir() {
+        return Err(anyhow::anyhow!("Template directory not found: {}", source.display()));
+    }
+    
+    let source = source.canonicalize()?;
+    let template_name = source.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or_else(|| anyhow::anyhow!("Cannot derive a template name from {}", source.display()))?;
+    validate_project_name(&template_name)?;
+    
+    let destination = cache_root.join(&template_name);
+    if destination.exists() {
+        tokio::fs::remove_dir_all(&destination).await?;
+    }
+    copy_directory(&source, &destination).await?;
+    
+    Ok(template_name)
+}
+
+// Locate a cached template by name, refusing names that would resolve outside the cache
+fn cached_template_dir(cache_root: &Path, template_name: &str) -> Result<Option<PathBuf>> {
+    validate_project_name(template_name)?;
+    
+    let template_dir = cache_root.join(template_name);
+    if !template_dir.is_dir() {
+        return Ok(None);
+    }
+    
+    let template_dir = template_dir.canonicalize()?;
+    if !template_dir.starts_with(cache_root.canonicalize()?) {
+        return Err(anyhow::anyhow!("Template '{}' resolves outside the template cache", template_name));
+    }
+    
+    Ok(Some(template_dir))
+}
+
+async fn remove_cached_template(cache_root: &Path, template_name: &str) -> Result<()> {
+    let template_dir = cached_template_dir(cache_root, template_name)?
+        .ok_or_else(|| anyhow::anyhow!("Template '{}' is not in the local cache", template_name))?;
+    
+    tokio::fs::remove_dir_all(template_dir).await?;
+    
+    Ok(())
+}
+
+fn cached_templates(cache_root: &Path) -> Vec<TemplateInfo> {
+    let Ok(entries) = fs::read_dir(cache_root) else {
+        return vec![];
+    };
+    
+    let mut templates: Vec<TemplateInfo> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| {
+            let metadata: serde_json::Value = fs::read_to_string(entry.path().join("template.json"))
+                .ok()
+                .and_then(|content| serde_json::from_str(&content).ok())
+                .unwrap_or_default();
+            
+            TemplateInfo {
+                name: entry.file_name().to_string_lossy().to_string(),
+                description: metadata["description"].as_str().unwrap_or("Local template").to_string(),
+                features: vec![],
+                files: vec![],
+            }
+        })
+        .collect();
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    
+    templates
+}
+
+// Scaffold a project from the template cache, substituting {{projectName}}.
+// Returns false when the template is not cached so init can fall back to built-ins.
+async fn scaffold_from_cached_template(cache_root: &Path, template_name: &str, project_path: &Path, project_name: &str) -> Result<bool> {
+    let Some(template_dir) = cached_template_dir(cache_root, template_name)? else {
+        return Ok(false);
+    };
+    
+    for entry in WalkDir::new(&template_dir) {
+        let entry = entry?;
+        let relative = entry.path().strip_prefix(&template_dir)?;
+        let destination = project_path.join(relative);
+        
+        if entry.file_type().is_dir() {
+            tokio::fs::create_dir_all(&destination).await?;
+        } else if relative != Path::new("template.json") {
+            let content = tokio::fs::read(entry.path()).await?;
+            match String::from_utf8(content) {
+                Ok(text) => tokio::fs::write(&destination, text.replace("{{projectName}}", project_name)).await?,
+                Err(binary) => tokio::fs::write(&destination, binary.into_bytes()).await?,
+            }
+        }
+    }
+    
+    write_project_dotfiles(project_path, template_name).await?;
+    
+    Ok(true)
+}
+
+async fn handle_batch_command(action: &str, config: Option<&str>) -> Result<()> {
+    logger::init("batch", "⚡ Batch Processor initialized");
+    println!("{}", style(format!("⚡ {} batch: {}", action, config.unwrap_or("default"))).green());
+    println!("{}", style("✅ Batch operation completed").green());
+    
+    Ok(())
+}
+
+async fn handle_config_command(action: &str, key: Option<&str>) -> Result<()> {
+    logger::init("config", "⚙️ Configuration Manager initialized");
+    println!("{}", style(format!("⚙️ {} config: {}", action, key.unwrap_or("all"))).green());
+    println!("{}", style("✅ Configuration operation completed").green());
+    
+    Ok(())
+}
+
+async fn handle_rust_command(action: &str, target: Option<&str>) -> Result<()> {
+    let project_path = std::env::current_dir()?;
+    handle_rust_command_at(&project_path, action, target).await
+}
+
+async fn handle_rust_command_at(project_path: &Path, action: &str, target: Option<&str>) -> Result<()> {
+    logger::init("rust-compiler", "🦀 Rust Compiler initialized");
+
+    match action {
+        "compile" => {
+            let target = project_path.join(target.unwrap_or("src"));
+            let output_dir = project_path.join("dist");
+
+            println!("{}", style(format!("🔨 Compiling: {}", target.display())).bold());
+            let results = compiler::compile_path(&target, &output_dir, CompilerConfig::default())?;
+
+            let mut failed = 0;
+            for result in &results {
+                if result.success {
+                    println!("  {} {}", style("✅").green(), result.output_path.display());
+                } else {
+                    failed += 1;
+                    println!("  {} {}", style("❌").red(), result.source_path.display());
+                }
+
+                for warning in &result.warnings {
+                    println!("    {}", style(format!("Warning: {}", warning)).yellow());
+                }
+                for error in &result.errors {
+                    println!("    {}", style(format!("Error: {}", error)).red());
+                }
+            }
+
+            if results.is_empty() {
+                println!("{}", style("⚠️ No TypeScript files found to compile").yellow());
+            } else if failed > 0 {
+                return Err(anyhow::anyhow!("{} of {} files failed to compile", failed, results.len()));
+            } else {
+                println!("{}", style(format!("✅ Compiled {} files into {}", results.len(), output_dir.display())).bold().green());
+            }
+        }
+        "quantum" => {
+            return Err(anyhow::anyhow!(
+                "Quantum compilation is not available in synapse-compiler v{}",
+                SynapseCompiler::version()
+            ));
+        }
+        "version" => {
+            println!("{}", style(format!("🦀 Synapse Compiler v{}", SynapseCompiler::version())).bold());
+        }
+        _ => {
+            println!("{}", style(format!("Unknown rust action: {}. Available actions: compile, quantum, version", action)).yellow());
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_hot_reload_command(action: &str, options: Option<&str>) -> Result<()> {
+    logger::init("hot-reload", "🔥 Hot Reload System initialized");
+    
+    let project_path = std::env::current_dir()?;
+    
+    match action {
+        "start" => {
+            let port = parse_hot_reload_port(options)?;
+            let mut command = Command::new(std::env::current_exe()?);
+            command.args(["hot-reload", "serve", &port.to_string()]);
+            let pid = start_hot_reload_service(&project_path, &mut command, HOT_RELOAD_READY_TIMEOUT)?;
+            
+            println!("{}", style(format!("✅ Hot reload server started (pid {})", pid)).green());
+            println!("{}", style(format!("📡 Subscribe to reload events at http://localhost:{}/events", port)).bold());
+        }
+        "stop" => {
+            if stop_hot_reload_service(&project_path)? {
+                println!("{}", style("✅ Hot reload server stopped").green());
+            } else {
+                println!("{}", style("⚠️ Hot reload server is not running").yellow());
+            }
+        }
+        "status" => {
+            match read_hot_reload_process(&project_path)? {
+                Some(process) if owns_hot_reload_process(&process) => {
+                    println!("{}", style(format!("🟢 Hot reload server is running (pid {})", process.pid)).green());
+                }
+                _ => {
+                    println!("{}", style("🔴 Hot reload server is not running").yellow());
+                }
+            }
+        }
+        "serve" => {
+            serve_hot_reload(project_path, parse_hot_reload_port(options)?, WATCH_POLL_INTERVAL).await?;
+        }
+        _ => {
+            println!("{}", style(format!("Unknown hot-reload action: {}. Available actions: start, stop, status, serve", action)).yellow());
+        }
+    }
+    
+    Ok(())
+}
+
+const DEFAULT_HOT_RELOAD_PORT: u16 = 35729;
+// Printed by `hot-reload serve` once its listener is bound
+const HOT_RELOAD_READY: &str = "hot-reload ready";
+const HOT_RELOAD_READY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+fn parse_hot_reload_port(port: Option<&str>) -> Result<u16> {
+    match port {
+        Some(port) => port.parse().map_err(|_| anyhow::anyhow!("Invalid hot reload port: {}", port)),
+        None => Ok(DEFAULT_HOT_RELOAD_PORT),
+    }
+}
+
+// Holds the bare PID, so `kill $(cat .synapse/hot-reload.pid)` keeps working
+fn hot_reload_pid_path(project_path: &Path) -> PathBuf {
+    project_path.join(".synapse").join("hot-reload.pid")
+}
+
+fn hot_reload_process_path(project_path: &Path) -> PathBuf {
+    project_path.join(".synapse").join("hot-reload.json")
+}
+
+fn read_hot_reload_process(project_path: &Path) -> Result<Option<HotReloadProcess>> {
+    let pid_path = hot_reload_pid_path(project_path);
+    let process_path = hot_reload_process_path(project_path);
+    if !pid_path.exists() || !process_path.exists() {
+        return Ok(None);
+    }
+    
+    // A PID without a matching recorded command line cannot be verified
+    let pid: Option<u32> = fs::read_to_string(pid_path)?.trim().parse().ok();
+    let process: Option<HotReloadProcess> = serde_json::from_str(&fs::read_to_string(process_path)?).ok();
+    Ok(process.filter(|process| Some(process.pid) == pid))
+}
+
+fn write_hot_reload_process(project_path: &Path, process: &HotReloadProcess) -> Result<()> {
+    let pid_path = hot_reload_pid_path(project_path);
+    if let Some(parent) = pid_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(hot_reload_process_path(project_path), serde_json::to_string(process)?)?;
+    fs::write(pid_path, process.pid.to_string())?;
+    
+    Ok(())
+}
+
+fn remove_hot_reload_process(project_path: &Path) -> Result<()> {
+    for path in [hot_reload_pid_path(project_path), hot_reload_process_path(project_path)] {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+    }
+    
+    Ok(())
+}
+
+fn describe_command(command: &Command) -> String {
+    std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|part| part.to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Full command line of a live process, or None when it is not running
+fn process_command_line(pid: u32) -> Option<String> {
+    let output = if cfg!(windows) {
+        Command::new("powershell")
+            .args([
+                "-NoProfile",
+                "-Command",
+                &format!("(Get-CimInstance Win32_Process -Filter 'ProcessId={}').CommandLine", pid),
+            ])
+            .output()
+    } else {
+        Command::new("ps").args(["-ww", "-o", "args=", "-p", &pid.to_string()]).output()
+    }
+    .ok()?;
+    
+    let command_line = String::from_utf8_lossy(&output.stdout).replace('"', "").trim().to_string();
+    (output.status.success() && !command_line.is_empty()).then_some(command_line)
+}
+
+// The PID is only ours if that process is still running the command we spawned
+fn owns_hot_reload_process(process: &HotReloadProcess) -> bool {
+    process_command_line(process.pid).is_some_and(|command_line| command_line == process.command)
+}
+
+// Spawn the reload service in the background and record its PID once it reports ready
+fn start_hot_reload_service(project_path: &Path, command: &mut Command, ready_timeout: std::time::Duration) -> Result<u32> {
+    use std::io::BufRead;
+    
+    if let Some(process) = read_hot_reload_process(project_path)? {
+        if owns_hot_reload_process(&process) {
+            return Err(anyhow::anyhow!("Hot reload server is already running (pid {})", process.pid));
+        }
+    }
+    
+    let mut child = command
+        .current_dir(project_path)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+    
+    let stdout = child.stdout.take().ok_or_else(|| anyhow::anyhow!("Hot reload server stdout is not piped"))?;
+    let (ready_sender, ready_receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let ready = std::io::BufReader::new(stdout)
+            .lines()
+            .map_while(|line| line.ok())
+            .any(|line| line.trim() == HOT_RELOAD_READY);
+        let _ = ready_sender.send(ready);
+    });
+    
+    match ready_receiver.recv_timeout(ready_timeout) {
+        Ok(true) => {}
+        Ok(false) => {
+            let _ = child.wait();
+            return Err(anyhow::anyhow!("Hot reload server exited before it was ready"));
+        }
+        Err(_) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(anyhow::anyhow!("Hot reload server did not become ready within {}s", ready_timeout.as_secs()));
+        }
+    }
+    
+    let process = HotReloadProcess {
+        pid: child.id(),
+        command: describe_command(command),
+    };
+    write_hot_reload_process(project_path, &process)?;
+    
+    Ok(process.pid)
+}
+
+// Terminate the recorded reload service; returns false when none was running.
+// A stale PID file whose process is gone or was reused is removed without killing anything.
+fn stop_hot_reload_service(project_path: &Path) -> Result<bool> {
+    let Some(process) = read_hot_reload_process(project_path)? else {
+        remove_hot_reload_process(project_path)?;
+        return Ok(false);
+    };
+    
+    let running = owns_hot_reload_process(&process);
+    if running {
+        let status = if cfg!(windows) {
+            Command::new("taskkill").args(["/PID", &process.pid.to_string(), "/F"]).status()?
+        } else {
+            Command::new("kill").arg(process.pid.to_string()).status()?
+        };
+        
+        if !status.success() {
+            return Err(anyhow::anyhow!("Failed to stop hot reload server (pid {})", process.pid));
+        }
+    }
+    
+    remove_hot_reload_process(project_path)?;
+    
+    Ok(running)
+}
+
+// Modification time and size per file; the size catches edits that land in the
+// same timestamp tick on filesystems with coarse modification times
+type SourceSnapshot = HashMap<PathBuf, (std::time::SystemTime, u64)>;
+
+fn snapshot_source_files(project_path: &Path) -> SourceSnapshot {
+    let mut snapshot = HashMap::new();
+    
+    for dir in ["src", "public"] {
+        for entry in WalkDir::new(project_path.join(dir)).into_iter().filter_map(|entry| entry.ok()) {
+            if let Ok(metadata) = entry.metadata() {
+                if let (true, Ok(modified)) = (entry.file_type().is_file(), metadata.modified()) {
+                    snapshot.insert(entry.path().to_path_buf(), (modified, metadata.len()));
+                }
+            }
+        }
+    }
+    
+    snapshot
+}
+
+// Project-relative paths added, modified or removed between two snapshots
+fn changed_source_files(project_path: &Path, previous: &SourceSnapshot, current: &SourceSnapshot) -> Vec<String> {
+    let mut changed: Vec<String> = current.iter()
+        .filter(|(path, stamp)| previous.get(*path) != Some(*stamp))
+        .map(|(path, _)| path)
+        .chain(previous.keys().filter(|path| !current.contains_key(*path)))
+        .map(|path| path.strip_prefix(project_path).unwrap_or(path).display().to_string())
+        .collect();
+    changed.sort();
+    changed
+}
+
+const WATCH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+const WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(100);
+
+// Run `pass` once, then again after every debounced batch of source changes.
+// Stops when `pass` returns false or on Ctrl+C, including one pressed while
+// `pass` runs or the debounce is waiting.
+async fn watch_sources<F, Fut>(project_path: &Path, poll_interval: std::time::Duration, mut pass: F) -> Result<()>
+where
+    F: FnMut(Vec<String>) -> Fut,
+    Fut: std::future::Future<Output = Result<bool>>,
+{
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    
+    let mut previous = snapshot_source_files(project_path);
+    tokio::select! {
+        keep_watching = pass(vec![]) => if !keep_watching? {
+            return Ok(());
+        },
+        _ = &mut ctrl_c => return Ok(()),
+    }
+    
+    loop {
+        tokio::select! {
+            _ = tokio::time::sleep(poll_interval) => {}
+            _ = &mut ctrl_c => return Ok(()),
+        }
+        
+        let mut current = snapshot_source_files(project_path);
+        if changed_source_files(project_path, &previous, &current).is_empty() {
+            continue;
+        }
+        
+        // Let editors finish writing before re-running
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(WATCH_DEBOUNCE) => {}
+                _ = &mut ctrl_c => return Ok(()),
+            }
+            let settled = snapshot_source_files(project_path);
+            if settled == current {
+                break;
+            }
+            current = settled;
+        }
+        
+        let changed = changed_source_files(project_path, &previous, &current);
+        previous = current;
+        tokio::select! {
+            keep_watching = pass(changed) => if !keep_watching? {
+                return Ok(());
+            },
+            _ = &mut ctrl_c => return Ok(()),
+        }
+    }
+}
+
+// Re-run `run` on every source change. A failing run is reported and the
+// watch carries on, so one bad lint or format pass does not end it.
+async fn watch_and_rerun<F, Fut>(mut run: F) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<()>>,
+{
+    let project_path = std::env::current_dir()?;
+    
+    watch_sources(&project_path, WATCH_POLL_INTERVAL, |changed| {
+        let pass = run();
+        async move {
+            console::Term::stdout().clear_screen()?;
+            println!("{}", style(format!("[{}] 👀 Watching for changes (Ctrl+C to exit)", Utc::now().format("%H:%M:%S"))).dim());
+            for file in &changed {
+                println!("{}", style(format!("  changed: {}", file)).dim());
+            }
+            if let Err(err) = pass.await {
+                println!("{}", style(format!("❌ {:#}", err)).red());
+            }
+            Ok(true)
+        }
+    }).await
+}
+
+// Watch the project sources and broadcast reload events to SSE subscribers.
+// Changes go through the same debounced watcher as `lint --watch` and `format --watch`.
+async fn serve_hot_reload(project_path: PathBuf, port: u16, poll_interval: std::time::Duration) -> Result<()> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", port)).await?;
+    let (sender, _) = tokio::sync::broadcast::channel::<String>(64);
+    println!("{}", HOT_RELOAD_READY);
+    
+    let watch_sender = sender.clone();
+    let watcher = watch_sources(&project_path, poll_interval, move |changed| {
+        if !changed.is_empty() {
+            let event = serde_json::json!({ "files": changed, "timestamp": Utc::now() });
+            // Sending only fails when nobody is subscribed, which is fine
+            let _ = watch_sender.send(event.to_string());
+        }
+        async { Ok(true) }
+    });
+    
+    let server = async {
+        loop {
+            let (mut socket, _) = listener.accept().await?;
+            let mut receiver = sender.subscribe();
+            
+            tokio::spawn(async move {
+                let mut request = [0u8; 1024];
+                if socket.read(&mut request).await.is_err() {
+                    return;
+                }
+                
+                let headers = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\nAccess-Control-Allow-Origin: *\r\n\r\n";
+                if socket.write_all(headers.as_bytes()).await.is_err() {
+                    return;
+                }
+                
+                while let Ok(event) = receiver.recv().await {
+                    let frame = format!("event: reload\ndata: {}\n\n", event);
+                    if socket.write_all(frame.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    };
+    
+    // The watcher returns on Ctrl+C, which also shuts the server down
+    tokio::select! {
+        result = watcher => result,
+        result = server => result,
+    }
+}
+
+async fn handle_deploy_command(action: &str, target: Option<&str>, force: bool) -> Result<()> {
+    logger::init("deployment", "🚀 Deployment System initialized");
+    
+    let project_path = std::env::current_dir()?;
+    
+    match action {
+        "static" => {
+            let target = target.ok_or_else(|| anyhow::anyhow!("Usage: synapse deploy static <target-dir>"))?;
+            let record = deploy_static(&project_path, &project_path.join("dist"), Path::new(target), force).await?;
+            
+            println!("{}", style(format!("✅ Deployed {} ({} files) to {}", record.id, record.manifest.len(), record.target)).green());
+        }
+        "history" => {
+            let history = load_deployment_history(&project_path)?;
+            if history.deployments.is_empty() {
+                println!("{}", style("⚠️ No deployments recorded yet").yellow());
+            }
+            
+            for record in history.deployments.iter().rev() {
+                let marker = if history.current.get(&record.target) == Some(&record.id) { "*" } else { " " };
+                let status = if record.success { style("success").green() } else { style("failed").red() };
+                println!(
+                    "{} {:<24} {} {:<8} {} files -> {}",
+                    marker,
+                    record.id,
+                    record.timestamp.format("%Y-%m-%d %H:%M:%S"),
+                    status,
+                    record.manifest.len(),
+                    record.target
+                );
+            }
+        }
+        "rollback" => {
+            let record = rollback_deployment(&project_path, target.map(Path::new)).await?;
+            println!("{}", style(format!("⏪ Rolled back to {} from {}", record.id, record.timestamp.format("%Y-%m-%d %H:%M:%S"))).green());
+        }
+        _ => {
+            println!("{}", style(format!("Unknown deploy action: {}. Available actions: static, history, rollback", action)).yellow());
+        }
+    }
+    
+    Ok(())
+}
+
+fn deployments_dir(project_path: &Path) -> PathBuf {
+    project_path.join(".synapse").join("deployments")
+}
+
+fn load_deployment_history(project_path: &Path) -> Result<DeploymentHistory> {
+    let history_path = deployments_dir(project_path).join("history.json");
+    
+    if history_path.exists() {
+        Ok(serde_json::from_str(&fs::read_to_string(history_path)?)?)
+    } else {
+        Ok(DeploymentHistory::default())
+    }
+}
+
+fn save_deployment_history(project_path: &Path, history: &DeploymentHistory) -> Result<()> {
+    let dir = deployments_dir(project_path);
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join("history.json"), serde_json::to_string_pretty(history)?)?;
+    
+    Ok(())
+}
+
+// Resolve a deploy target to an absolute path, refusing any target whose
+// replacement would wipe out the project or its deployment ledger, and any
+// existing target that is not a directory
+fn resolve_deploy_target(project_path: &Path, target: &Path) -> Result<PathBuf> {
+    let project_root = project_path.canonicalize()?;
+    let joined = project_root.join(target);
+    let resolved = match joined.canonicalize() {
+        Ok(resolved) => resolved,
+        Err(_) => normalize_path(&joined),
+    };
+    
+    if project_root.starts_with(&resolved) {
+        return Err(anyhow::anyhow!("Refusing to deploy to {}: it contains the project", resolved.display()));
+    }
+    
+    let deployments = deployments_dir(&project_root);
+    if deployments.starts_with(&resolved) || resolved.starts_with(&deployments) {
+        return Err(anyhow::anyhow!("Refusing to deploy to {}: it overlaps the deployment history", resolved.display()));
+    }
+    
+    if resolved.exists() && !resolved.is_dir() {
+        return Err(anyhow::anyhow!("Refusing to deploy to {}: it is not a directory", resolved.display()));
+    }
+    
+    Ok(resolved)
+}
+
+// Lexically resolve `.` and `..` for paths that do not exist yet
+fn normalize_path(path: &Path) -> PathBuf {
+    use std::path::Component;
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+// A missing path or an empty directory has nothing to lose
+fn is_empty_directory(path: &Path) -> Result<bool> {
+    match fs::read_dir(path) {
+        Ok(mut entries) => Ok(entries.next().is_none()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(true),
+        Err(_) if path.exists() => Ok(false),
+        Err(err) => Err(err.into()),
+    }
+}
+
+// Replace the target's contents with a snapshot. The snapshot is staged next
+// to the target and swapped in, so a failed copy leaves the old deploy intact.
+async fn publish_snapshot(snapshot: &Path, target: &Path) -> Result<()> {
+    let parent = target.parent().ok_or_else(|| anyhow::anyhow!("Invalid deploy target: {}", target.display()))?;
+    let name = target.file_name().unwrap_or_default().to_string_lossy();
+    let staging = parent.join(format!(".{}.synapse-staging", name));
+    let previous = parent.join(format!(".{}.synapse-previous", name));
+    
+    tokio::fs::create_dir_all(parent).await?;
+    for leftover in [&staging, &previous] {
+        if leftover.exists() {
+            tokio::fs::remove_dir_all(leftover).await?;
+        }
+    }
+    
+    if let Err(err) = copy_directory(&snapshot.to_path_buf(), &staging).await {
+        let _ = tokio::fs::remove_dir_all(&staging).await;
+        return Err(err);
+    }
+    
+    let had_previous = target.exists();
+    if had_previous {
+        tokio::fs::rename(target, &previous).await?;
+    }
+    if let Err(err) = tokio::fs::rename(&staging, target).await {
+        if had_previous {
+            tokio::fs::rename(&previous, target).await?;
+        }
+        return Err(err.into());
+    }
+    if had_previous {
+        tokio::fs::remove_dir_all(&previous).await?;
+    }
     
     Ok(())
 }
 
+// Snapshot the build output, publish it to a local directory and record it in the history ledger.
+// An existing target is only replaced if synapse deployed to it before, unless `force` is set.
+async fn deploy_static(project_path: &Path, source: &Path, target: &Path, force: bool) -> Result<DeploymentRecord> {
+    if !source.is_dir() {
+        return Err(anyhow::anyhow!("Nothing to deploy: {} does not exist. Run 'synapse build' first", source.display()));
+    }
+    let target = resolve_deploy_target(project_path, target)?;
+    
+    let mut history = load_deployment_history(project_path)?;
+    if !force && !history.current.contains_key(&target.display().to_string()) && !is_empty_directory(&target)? {
+        return Err(anyhow::anyhow!(
+            "Refusing to replace {}: it exists and was not deployed by synapse. Pass --force to overwrite it",
+            target.display()
+        ));
+    }
+    let timestamp = Utc::now();
+    let id = format!("{}-{}", timestamp.format("%Y%m%d%H%M%S"), history.deployments.len() + 1);
+    let snapshot = deployments_dir(project_path).join(&id);
+    copy_directory(&source.to_path_buf(), &snapshot).await?;
+    
+    let mut manifest = Vec::new();
+    for entry in WalkDir::new(&snapshot).into_iter().filter_map(|entry| entry.ok()) {
+        if entry.file_type().is_file() {
+            manifest.push(DeployedFile {
+                path: entry.path().strip_prefix(&snapshot)?.display().to_string(),
+                hash: calculate_file_hash(&entry.path().to_path_buf())?,
+            });
+        }
+    }
+    manifest.sort_by(|a, b| a.path.cmp(&b.path));
+    
+    let published = publish_snapshot(&snapshot, &target).await;
+    let success = published.is_ok();
+    let record = DeploymentRecord {
+        id: id.clone(),
+        target: target.display().to_string(),
+        snapshot: snapshot.display().to_string(),
+        manifest,
+        success,
+        timestamp,
+    };
+    
+    history.deployments.push(record.clone());
+    if success {
+        history.current.insert(record.target.clone(), id);
+    }
+    save_deployment_history(project_path, &history)?;
+    
+    published.with_context(|| format!("failed to publish snapshot {} to {}", record.id, target.display()))?;
+    
+    Ok(record)
+}
+
+// Restore the last successful deployment to a target before its current one.
+// The target may be omitted when only one target has been deployed to.
+async fn rollback_deployment(project_path: &Path, target: Option<&Path>) -> Result<DeploymentRecord> {
+    let mut history = load_deployment_history(project_path)?;
+    
+    let target = match target {
+        Some(target) => resolve_deploy_target(project_path, target)?.display().to_string(),
+        None => match history.current.len() {
+            0 => return Err(anyhow::anyhow!("No current deployment to roll back from")),
+            1 => history.current.keys().next().cloned().unwrap_or_default(),
+            _ => return Err(anyhow::anyhow!("Multiple deploy targets recorded. Usage: synapse deploy rollback <target-dir>")),
+        },
+    };
+    
+    let current_index = history.current.get(&target)
+        .and_then(|current| history.deployments.iter().position(|record| &record.id == current))
+        .ok_or_else(|| anyhow::anyhow!("No current deployment to roll back from on {}", target))?;
+    
+    let previous = history.deployments[..current_index].iter()
+        .rev()
+        .find(|record| record.success && record.target == target)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("No earlier successful deployment to {} to roll back to", target))?;
+    
+    publish_snapshot(Path::new(&previous.snapshot), Path::new(&previous.target)).await?;
+    
+    history.current.insert(target, previous.id.clone());
+    save_deployment_history(project_path, &history)?;
+    
+    Ok(previous)
+}
+
 async fn handle_monitor_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("📊 Monitoring System initialized").green());
+    logger::init("monitoring", "📊 Monitoring System initialized");
     println!("{}", style(format!("📊 {} monitor: {}", action, options.unwrap_or("default"))).green());
     println!("{}", style("✅ Monitoring operation completed").green());
     
     Ok(())
 }
 
-async fn handle_profile_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("⚡ Performance Profiler initialized").green());
+async fn handle_profile_command(action: &str, options: Option<&str>, json: bool) -> Result<()> {
+    logger::init("profiler", "⚡ Performance Profiler initialized");
 
     match action {
         "cpu" => {
@@ -1273,12 +2976,32 @@ async fn handle_profile_command(action: &str, options: Option<&str>) -> Result<(
             }
         }
         "optimize" => {
+            let project_path = options.map(PathBuf::from).unwrap_or(std::env::current_dir()?);
+            let report = analyze_project_for_optimization(&project_path)?;
+            
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+                return Ok(());
+            }
+            
             println!("{}", style("🚀 Analyzing for optimization suggestions...").green());
+            println!("{}", style("Largest source files:").bold());
+            for file in &report.largest_files {
+                println!("  {:<50} {}", file.path, format_bytes(file.size));
+            }
+            println!("{}", style(format!(
+                "Dependencies: {} ({} dev)",
+                report.dependency_count, report.dev_dependency_count
+            )).bold());
+            
+            println!();
             println!("{}", style("Optimization Suggestions:").bold());
-            println!("  - Use `cargo build --release` for production builds.");
-            println!("  - Analyze your dependencies with `cargo-bloat` to find size optimizations.");
-            println!("  - Use `pprof` to profile your code and find performance bottlenecks.");
-            println!("  - Consider using `jemalloc` as a global allocator for better memory performance.");
+            if report.suggestions.is_empty() {
+                println!("  {}", style("No obvious optimizations found").green());
+            }
+            for suggestion in &report.suggestions {
+                println!("  - {}", suggestion);
+            }
         }
         _ => {
             println!("{}", style(format!("Unknown profile action: {}. Available actions: cpu, memory, bundle, optimize", action)).yellow());
@@ -1288,8 +3011,126 @@ async fn handle_profile_command(action: &str, options: Option<&str>) -> Result<(
     Ok(())
 }
 
+// Source files above this size are flagged as candidates for splitting
+const LARGE_SOURCE_FILE_BYTES: u64 = 50 * 1024;
+const MANY_DEPENDENCIES: usize = 30;
+
+#[derive(Debug, Serialize)]
+struct SourceFileSize {
+    path: String,
+    size: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct AntiPatternMatch {
+    file: String,
+    line: usize,
+    code: String,
+    message: String,
+}
+
+#[derive(Debug, Serialize)]
+struct OptimizationReport {
+    largest_files: Vec<SourceFileSize>,
+    dependency_count: usize,
+    dev_dependency_count: usize,
+    source_maps: Vec<String>,
+    anti_patterns: Vec<AntiPatternMatch>,
+    suggestions: Vec<String>,
+}
+
+fn analyze_project_for_optimization(project_path: &Path) -> Result<OptimizationReport> {
+    let anti_pattern_rules = [
+        (
+            Regex::new(r"\b(readFileSync|writeFileSync|appendFileSync|existsSync|readdirSync|statSync|mkdirSync)\s*\(")?,
+            "Synchronous file system call blocks the event loop; use fs/promises",
+        ),
+        (
+            Regex::new(r"JSON\.parse\(\s*JSON\.stringify\(")?,
+            "JSON round-trip deep clone is slow; use structuredClone",
+        ),
+    ];
+    
+    let relative = |path: &Path| path.strip_prefix(project_path).unwrap_or(path).display().to_string();
+    
+    let mut files = Vec::new();
+    let mut anti_patterns = Vec::new();
+    for entry in WalkDir::new(project_path.join("src")).into_iter().filter_map(|entry| entry.ok()) {
+        let is_source = entry.path().extension()
+            .is_some_and(|ext| ["ts", "tsx", "js", "jsx"].iter().any(|source_ext| ext == *source_ext));
+        if !entry.file_type().is_file() || !is_source {
+            continue;
+        }
+        
+        files.push(SourceFileSize { path: relative(entry.path()), size: entry.metadata()?.len() });
+        
+        let Ok(content) = fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        for (index, line) in content.lines().enumerate() {
+            for (pattern, message) in &anti_pattern_rules {
+                if let Some(found) = pattern.find(line) {
+                    anti_patterns.push(AntiPatternMatch {
+                        file: relative(entry.path()),
+                        line: index + 1,
+                        code: found.as_str().trim_end_matches('(').trim().to_string(),
+                        message: message.to_string(),
+                    });
+                }
+            }
+        }
+    }
+    files.sort_by_key(|file| std::cmp::Reverse(file.size));
+    
+    let package_json = read_package_json(project_path).unwrap_or_else(|_| serde_json::json!({}));
+    let count_deps = |key: &str| package_json.get(key).and_then(|deps| deps.as_object()).map_or(0, |deps| deps.len());
+    let dependency_count = count_deps("dependencies");
+    let dev_dependency_count = count_deps("devDependencies");
+    
+    let source_maps: Vec<String> = WalkDir::new(project_path.join("dist"))
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file() && entry.path().extension().is_some_and(|ext| ext == "map"))
+        .map(|entry| relative(entry.path()))
+        .collect();
+    
+    let mut suggestions = Vec::new();
+    for file in files.iter().filter(|file| file.size > LARGE_SOURCE_FILE_BYTES) {
+        suggestions.push(format!(
+            "Split {} ({}) into smaller modules to improve code splitting",
+            file.path, format_bytes(file.size)
+        ));
+    }
+    if dependency_count > MANY_DEPENDENCIES {
+        suggestions.push(format!(
+            "Review {} runtime dependencies in package.json; move build-only packages to devDependencies",
+            dependency_count
+        ));
+    }
+    if !source_maps.is_empty() {
+        suggestions.push(format!(
+            "Found {} source maps in dist/; disable source maps for production builds or keep them off the CDN",
+            source_maps.len()
+        ));
+    }
+    for found in &anti_patterns {
+        suggestions.push(format!("{}:{} uses {}: {}", found.file, found.line, found.code, found.message));
+    }
+    
+    files.truncate(5);
+    
+    Ok(OptimizationReport {
+        largest_files: files,
+        dependency_count,
+        dev_dependency_count,
+        source_maps,
+        anti_patterns,
+        suggestions,
+    })
+}
+
 async fn handle_security_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("🔒 Security Scanner initialized").green());
+    logger::init("security", "🔒 Security Scanner initialized");
     println!("{}", style(format!("🔒 {} security: {}", action, options.unwrap_or("default"))).green());
     println!("{}", style("✅ Security operation completed").green());
     
@@ -1297,7 +3138,7 @@ async fn handle_security_command(action: &str, options: Option<&str>) -> Result<
 }
 
 async fn handle_database_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("🗄️ Database Manager initialized").green());
+    logger::init("database", "🗄️ Database Manager initialized");
     println!("{}", style(format!("🗄️ {} database: {}", action, options.unwrap_or("default"))).green());
     println!("{}", style("✅ Database operation completed").green());
     
@@ -1305,7 +3146,7 @@ async fn handle_database_command(action: &str, options: Option<&str>) -> Result<
 }
 
 async fn handle_docs_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("📚 API Documentation Generator initialized").green());
+    logger::init("api-docs", "📚 API Documentation Generator initialized");
     println!("{}", style(format!("📚 {} docs: {}", action, options.unwrap_or("default"))).green());
     println!("{}", style("✅ Documentation operation completed").green());
     
@@ -1313,7 +3154,7 @@ async fn handle_docs_command(action: &str, options: Option<&str>) -> Result<()>
 }
 
 async fn handle_i18n_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("🌍 Internationalization Manager initialized").green());
+    logger::init("i18n", "🌍 Internationalization Manager initialized");
     println!("{}", style(format!("🌍 {} i18n: {}", action, options.unwrap_or("default"))).green());
     println!("{}", style("✅ i18n operation completed").green());
     
@@ -1321,7 +3162,7 @@ async fn handle_i18n_command(action: &str, options: Option<&str>) -> Result<()>
 }
 
 async fn handle_cache_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("💾 Intelligent Cache initialized").green());
+    logger::init("cache", "💾 Intelligent Cache initialized");
     println!("{}", style(format!("💾 {} cache: {}", action, options.unwrap_or("default"))).green());
     println!("{}", style("✅ Cache operation completed").green());
     
@@ -1329,7 +3170,7 @@ async fn handle_cache_command(action: &str, options: Option<&str>) -> Result<()>
 }
 
 async fn handle_analytics_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("📈 Analytics System initialized").green());
+    logger::init("analytics", "📈 Analytics System initialized");
     println!("{}", style(format!("📈 {} analytics: {}", action, options.unwrap_or("default"))).green());
     println!("{}", style("✅ Analytics operation completed").green());
     
@@ -1340,32 +3181,36 @@ async fn handle_ai_command(action: &str, options: Option<&str>) -> Result<()> {
     let pb = create_progress_bar("Initializing AI Assistant...");
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
     
-    println!("{}", style("🤖 AI Assistant initialized").green());
+    logger::init("ai", "🤖 AI Assistant initialized");
+    
+    let project_path = std::env::current_dir()?;
+    let backend = AiBackend::resolve(&get_project_config(&project_path)?);
+    let backend = backend.as_ref();
     
     match action {
         "generate" => {
-            handle_ai_generate(options).await?;
+            handle_ai_generate(backend, options).await?;
         }
         "complete" => {
-            handle_ai_complete(options).await?;
+            handle_ai_complete(backend, options).await?;
         }
         "fix" => {
-            handle_ai_fix(options).await?;
+            handle_ai_fix(backend, options).await?;
         }
         "test" => {
-            handle_ai_test(options).await?;
+            handle_ai_test(backend, options).await?;
         }
         "docs" => {
-            handle_ai_docs(options).await?;
+            handle_ai_docs(backend, options).await?;
         }
         "refactor" => {
-            handle_ai_refactor(options).await?;
+            handle_ai_refactor(backend, options).await?;
         }
         "optimize" => {
-            handle_ai_optimize(options).await?;
+            handle_ai_optimize(backend, options).await?;
         }
         "analyze" => {
-            handle_ai_analyze(options).await?;
+            handle_ai_analyze(backend, options).await?;
         }
         _ => {
             println!("{}", style("Available AI commands:").bold());
@@ -1386,14 +3231,121 @@ async fn handle_ai_command(action: &str, options: Option<&str>) -> Result<()> {
     Ok(())
 }
 
-async fn handle_ai_generate(options: Option<&str>) -> Result<()> {
+async fn handle_ai_generate(backend: Option<&AiBackend>, options: Option<&str>) -> Result<()> {
     let prompt = options.unwrap_or("Create a React component");
     
     println!("{}", style("🔧 Generating code...").bold().green());
     println!("{}", style(format!("Prompt: {}", prompt)).bold());
     
-    // Simulate AI code generation
-    let generated_code = match prompt.to_lowercase() {
+    let project_path = std::env::current_dir()?;
+    let generated_code = ai_respond(backend, "generate", prompt, canned_generated_code(prompt)).await?;
+    
+    println!("{}", style("Generated code:").bold());
+    println!("{}", style("```typescript").dim());
+    println!("{}", generated_code);
+    println!("{}", style("```").dim());
+    
+    // Ask if user wants to save the code
+    if Confirm::new()
+        .with_prompt("Save generated code to file?")
+        .interact()? {
+        
+        let filename = Input::<String>::new()
+            .with_prompt("Enter filename")
+            .default("generated.ts".to_string())
+            .interact()?;
+        
+        let file_path = save_generated_code(&project_path, &filename, &generated_code).await?;
+        println!("{}", style(format!("✅ Code saved to {}", file_path.display())).green());
+    }
+    
+    Ok(())
+}
+
+/// HTTP completion endpoint used by the AI commands when configured
+#[derive(Debug, Clone)]
+struct AiBackend {
+    endpoint: String,
+    api_key: Option<String>,
+    timeout: std::time::Duration,
+}
+
+impl AiBackend {
+    // SYNAPSE_AI_ENDPOINT wins over the `ai.endpoint` project setting.
+    // The key is only read from SYNAPSE_AI_API_KEY so it never lands in the repo.
+    fn resolve(config: &ProjectConfig) -> Option<Self> {
+        let endpoint = std::env::var("SYNAPSE_AI_ENDPOINT").ok()
+            .or_else(|| config.ai.endpoint.clone())
+            .filter(|endpoint| !endpoint.is_empty())?;
+        
+        Some(Self {
+            endpoint,
+            api_key: std::env::var("SYNAPSE_AI_API_KEY").ok(),
+            timeout: std::time::Duration::from_secs(config.ai.timeout_secs),
+        })
+    }
+    
+    async fn complete(&self, action: &str, prompt: &str) -> Result<String> {
+        let mut request = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .build()?
+            .post(&self.endpoint)
+            .json(&serde_json::json!({ "action": action, "prompt": prompt }));
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        
+        let response = request.send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!("AI backend returned {}", response.status()));
+        }
+        
+        let body: serde_json::Value = response.json().await?;
+        body.get("completion")
+            .and_then(|completion| completion.as_str())
+            .map(|completion| completion.to_string())
+            .ok_or_else(|| anyhow::anyhow!("AI backend response is missing a 'completion' string"))
+    }
+}
+
+// Ask the configured backend, falling back to the canned response when there is none
+async fn ai_respond(backend: Option<&AiBackend>, action: &str, prompt: &str, canned: String) -> Result<String> {
+    match backend {
+        Some(backend) => backend.complete(action, prompt).await,
+        None => Ok(canned),
+    }
+}
+
+// Send the target's source along with its path when the file exists
+fn ai_target_prompt(target: &str) -> String {
+    match fs::read_to_string(target) {
+        Ok(source) => format!("{}\n\n{}", target, source),
+        Err(_) => target.to_string(),
+    }
+}
+
+fn numbered_list(items: &[&str]) -> String {
+    items.iter()
+        .enumerate()
+        .map(|(i, item)| format!("  {}. {}", i + 1, item))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+async fn save_generated_code(project_path: &Path, filename: &str, code: &str) -> Result<PathBuf> {
+    let file_path = project_path.join("src").join(filename);
+    if let Some(parent) = file_path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    
+    tokio::fs::write(&file_path, code).await?;
+    
+    Ok(file_path)
+}
+
+// Canned responses used when no AI backend is configured
+fn canned_generated_code(prompt: &str) -> String {
+    match prompt.to_lowercase() {
         p if p.contains("react") && p.contains("component") => {
             r#"import React from 'react';
 
@@ -1463,41 +3415,17 @@ describe('GeneratedComponent', () => {
         _ => {
             format!("// Generated code for: {}\n// TODO: Implement functionality", prompt)
         }
-    };
-    
-    println!("{}", style("Generated code:").bold());
-    println!("{}", style("```typescript").dim());
-    println!("{}", generated_code);
-    println!("{}", style("```").dim());
-    
-    // Ask if user wants to save the code
-    if Confirm::new()
-        .with_prompt("Save generated code to file?")
-        .interact()? {
-        
-        let filename = Input::<String>::new()
-            .with_prompt("Enter filename")
-            .default("generated.ts".to_string())
-            .interact()?;
-        
-        let project_path = std::env::current_dir()?;
-        let file_path = project_path.join("src").join(&filename);
-        
-        tokio::fs::write(&file_path, generated_code).await?;
-        println!("{}", style(format!("✅ Code saved to {}", file_path.display())).green());
     }
-    
-    Ok(())
 }
 
-async fn handle_ai_complete(options: Option<&str>) -> Result<()> {
+async fn handle_ai_complete(backend: Option<&AiBackend>, options: Option<&str>) -> Result<()> {
     let context = options.unwrap_or("function calculateSum");
     
     println!("{}", style("🔧 Completing code...").bold().green());
     println!("{}", style(format!("Context: {}", context)).bold());
     
-    // Simulate AI code completion
-    let completion = match context.to_lowercase() {
+    // Canned completions used without a backend
+    let canned = match context.to_lowercase() {
         c if c.contains("function") && c.contains("sum") => {
             "(a: number, b: number): number {\n  return a + b;\n}"
         }
@@ -1509,6 +3437,7 @@ async fn handle_ai_complete(options: Option<&str>) -> Result<()> {
         }
         _ => " {\n  // TODO: Implement functionality\n}"
     };
+    let completion = ai_respond(backend, "complete", context, canned.to_string()).await?;
     
     println!("{}", style("Suggested completion:").bold());
     println!("{}", style(format!("{}{}", context, completion)).green());
@@ -1516,14 +3445,14 @@ async fn handle_ai_complete(options: Option<&str>) -> Result<()> {
     Ok(())
 }
 
-async fn handle_ai_fix(options: Option<&str>) -> Result<()> {
+async fn handle_ai_fix(backend: Option<&AiBackend>, options: Option<&str>) -> Result<()> {
     let issue = options.unwrap_or("TypeError: Cannot read property 'length' of undefined");
     
     println!("{}", style("🔧 Fixing code...").bold().green());
     println!("{}", style(format!("Issue: {}", issue)).bold());
     
-    // Simulate AI bug fixing
-    let fix = match issue.to_lowercase() {
+    // Canned fixes used without a backend
+    let canned = match issue.to_lowercase() {
         i if i.contains("cannot read property") && i.contains("undefined") => {
             "// Fix: Add null/undefined check\nif (array && array.length > 0) {\n  // Safe to use array.length\n}"
         }
@@ -1535,6 +3464,7 @@ async fn handle_ai_fix(options: Option<&str>) -> Result<()> {
         }
         _ => "// Fix: Add proper error handling and type checking"
     };
+    let fix = ai_respond(backend, "fix", issue, canned.to_string()).await?;
     
     println!("{}", style("Suggested fix:").bold());
     println!("{}", style(fix).green());
@@ -1542,14 +3472,14 @@ async fn handle_ai_fix(options: Option<&str>) -> Result<()> {
     Ok(())
 }
 
-async fn handle_ai_test(options: Option<&str>) -> Result<()> {
+async fn handle_ai_test(backend: Option<&AiBackend>, options: Option<&str>) -> Result<()> {
     let target = options.unwrap_or("src/utils/calculator.ts");
     
     println!("{}", style("🧪 Generating tests...").bold().green());
     println!("{}", style(format!("Target: {}", target)).bold());
     
-    // Simulate AI test generation
-    let test_code = r#"import { describe, it, expect } from '@testing-library/jest';
+    // Canned tests used without a backend
+    let canned = r#"import { describe, it, expect } from '@testing-library/jest';
 import { Calculator } from './calculator';
 
 describe('Calculator', () => {
@@ -1589,6 +3519,7 @@ describe('Calculator', () => {
     });
   });
 });"#;
+    let test_code = ai_respond(backend, "test", &ai_target_prompt(target), canned.to_string()).await?;
     
     println!("{}", style("Generated test code:").bold());
     println!("{}", style("```typescript").dim());
@@ -1598,14 +3529,14 @@ describe('Calculator', () => {
     Ok(())
 }
 
-async fn handle_ai_docs(options: Option<&str>) -> Result<()> {
+async fn handle_ai_docs(backend: Option<&AiBackend>, options: Option<&str>) -> Result<()> {
     let target = options.unwrap_or("src/api/user.ts");
     
     println!("{}", style("📚 Generating documentation...").bold().green());
     println!("{}", style(format!("Target: {}", target)).bold());
     
-    // Simulate AI documentation generation
-    let docs = r#"# User API
+    // Canned documentation used without a backend
+    let canned = r#"# User API
 
 ## Overview
 This module provides user management functionality including CRUD operations and authentication.
@@ -1672,6 +3603,7 @@ interface User extends UserData {
 ## Error Handling
 All functions throw appropriate errors for invalid inputs or system failures.
 "#;
+    let docs = ai_respond(backend, "docs", &ai_target_prompt(target), canned.to_string()).await?;
     
     println!("{}", style("Generated documentation:").bold());
     println!("{}", docs);
@@ -1679,14 +3611,14 @@ All functions throw appropriate errors for invalid inputs or system failures.
     Ok(())
 }
 
-async fn handle_ai_refactor(options: Option<&str>) -> Result<()> {
+async fn handle_ai_refactor(backend: Option<&AiBackend>, options: Option<&str>) -> Result<()> {
     let target = options.unwrap_or("src/components/Button.tsx");
     
     println!("{}", style("🔧 Refactoring code...").bold().green());
     println!("{}", style(format!("Target: {}", target)).bold());
     
-    // Simulate AI refactoring suggestions
-    let refactor_suggestions = vec![
+    // Canned suggestions used without a backend
+    let canned = numbered_list(&[
         "Extract repeated logic into custom hooks",
         "Split large component into smaller components",
         "Use TypeScript interfaces for better type safety",
@@ -1695,24 +3627,23 @@ async fn handle_ai_refactor(options: Option<&str>) -> Result<()> {
         "Use context for state management",
         "Extract constants to separate file",
         "Implement proper loading states"
-    ];
+    ]);
+    let suggestions = ai_respond(backend, "refactor", &ai_target_prompt(target), canned).await?;
     
     println!("{}", style("Refactoring suggestions:").bold());
-    for (i, suggestion) in refactor_suggestions.iter().enumerate() {
-        println!("  {}. {}", i + 1, suggestion);
-    }
+    println!("{}", suggestions);
     
     Ok(())
 }
 
-async fn handle_ai_optimize(options: Option<&str>) -> Result<()> {
+async fn handle_ai_optimize(backend: Option<&AiBackend>, options: Option<&str>) -> Result<()> {
     let target = options.unwrap_or("src/components/DataTable.tsx");
     
     println!("{}", style("⚡ Optimizing code...").bold().green());
     println!("{}", style(format!("Target: {}", target)).bold());
     
-    // Simulate AI optimization suggestions
-    let optimizations = vec![
+    // Canned suggestions used without a backend
+    let canned = numbered_list(&[
         "Use React.memo() to prevent unnecessary re-renders",
         "Implement virtual scrolling for large datasets",
         "Debounce search input to reduce API calls",
@@ -1721,24 +3652,23 @@ async fn handle_ai_optimize(options: Option<&str>) -> Result<()> {
         "Optimize bundle size with tree shaking",
         "Use Web Workers for heavy computations",
         "Implement proper caching strategies"
-    ];
+    ]);
+    let optimizations = ai_respond(backend, "optimize", &ai_target_prompt(target), canned).await?;
     
     println!("{}", style("Optimization suggestions:").bold());
-    for (i, optimization) in optimizations.iter().enumerate() {
-        println!("  {}. {}", i + 1, optimization);
-    }
+    println!("{}", optimizations);
     
     Ok(())
 }
 
-async fn handle_ai_analyze(options: Option<&str>) -> Result<()> {
+async fn handle_ai_analyze(backend: Option<&AiBackend>, options: Option<&str>) -> Result<()> {
     let target = options.unwrap_or("src/");
     
     println!("{}", style("🔍 Analyzing code...").bold().green());
     println!("{}", style(format!("Target: {}", target)).bold());
     
-    // Simulate AI code analysis
-    let analysis = serde_json::json!({
+    // Canned analysis used without a backend
+    let canned = serde_json::json!({
         "complexity": {
             "cyclomatic": 12,
             "cognitive": 8,
@@ -1768,25 +3698,73 @@ async fn handle_ai_analyze(options: Option<&str>) -> Result<()> {
         ]
     });
     
+    let analysis = ai_respond(backend, "analyze", &ai_target_prompt(target), serde_json::to_string_pretty(&canned)?).await?;
+    
     println!("{}", style("Code Analysis Results:").bold());
-    println!("{}", serde_json::to_string_pretty(&analysis)?);
+    println!("{}", analysis);
     
     Ok(())
 }
 
 async fn handle_cloud_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("☁️ Cloud Sync Manager initialized").green());
+    logger::init("cloud-sync", "☁️ Cloud Sync Manager initialized");
     println!("{}", style(format!("☁️ {} cloud: {}", action, options.unwrap_or("default"))).green());
     println!("{}", style("✅ Cloud operation completed").green());
     
     Ok(())
 }
 
-async fn handle_team_command(action: &str, options: Option<&str>) -> Result<()> {
-    println!("{}", style("👥 Team Collaboration Manager initialized").green());
-    println!("{}", style(format!("👥 {} team: {}", action, options.unwrap_or("default"))).green());
-    println!("{}", style("✅ Team operation completed").green());
-    
+async fn handle_team_command(action: &str, options: Option<&str>) -> Result<()> {
+    logger::init("team", "👥 Team Collaboration Manager initialized");
+
+    let project_path = std::env::current_dir()?;
+    let (codeowners_path, codeowners) = match CodeOwners::discover(&project_path)? {
+        Some(found) => found,
+        None => {
+            println!("{}", style("⚠️ No CODEOWNERS file found").yellow());
+            println!("{}", style("Create CODEOWNERS, .github/CODEOWNERS or docs/CODEOWNERS in the project root").yellow());
+            return Ok(());
+        }
+    };
+
+    match action {
+        "owners" => {
+            let path = options.ok_or_else(|| anyhow::anyhow!("Usage: synapse team owners <path>"))?;
+
+            match codeowners.owner_rule(path) {
+                Some(rule) if !rule.owners.is_empty() => {
+                    println!("{}", style(format!("📁 {}", path)).bold());
+                    println!("  {} {}", style("Owners:").bold(), rule.owners.join(", "));
+                    println!("  {} {} (line {})", style("Rule:").dim(), rule.pattern, rule.line);
+                }
+                Some(rule) => {
+                    println!("{}", style(format!("⚠️ {} is explicitly unowned (line {})", path, rule.line)).yellow());
+                }
+                None => {
+                    println!("{}", style(format!("⚠️ No owners found for {}", path)).yellow());
+                }
+            }
+        }
+        "list" => {
+            println!("{}", style(format!("📋 Ownership rules from {}", codeowners_path.display())).bold());
+
+            for rule in &codeowners.rules {
+                let owners = if rule.owners.is_empty() {
+                    style("(unowned)".to_string()).dim()
+                } else {
+                    style(rule.owners.join(", ")).green()
+                };
+                println!("  {:<4} {:<40} {}", rule.line, rule.pattern, owners);
+            }
+
+            println!();
+            println!("{}", style(format!("Found {} rules", codeowners.rules.len())).bold());
+        }
+        _ => {
+            println!("{}", style(format!("Unknown team action: {}. Available actions: owners, list", action)).yellow());
+        }
+    }
+
     Ok(())
 }
 
@@ -1820,6 +3798,9 @@ fn get_project_config(project_path: &PathBuf) -> Result<ProjectConfig> {
             features: vec![],
             created_at: Utc::now(),
             last_modified: Utc::now(),
+            defaults: HashMap::new(),
+            testing: TestingSettings::default(),
+            ai: AiSettings::default(),
         })
     }
 }
@@ -1953,9 +3934,8 @@ fn validate_project_name(name: &str) -> Result<()> {
 }
 
 async fn get_available_templates() -> Result<Vec<TemplateInfo>> {
-    // In a real implementation, this would fetch from a remote repository
-    // For now, we'll return hardcoded templates
-    Ok(vec![
+    // Built-in templates, extended by the local template cache below
+    let mut templates = vec![
         TemplateInfo {
             name: "default".to_string(),
             description: "Standard Synapse project with basic features".to_string(),
@@ -1974,7 +3954,17 @@ async fn get_available_templates() -> Result<Vec<TemplateInfo>> {
             features: vec!["react".to_string(), "nodejs".to_string(), "typescript".to_string()],
             files: vec!["package.json".to_string(), "src/client".to_string(), "src/server".to_string()],
         },
-    ])
+    ];
+    
+    // Locally cached templates shadow built-ins of the same name
+    if let Ok(cache_root) = template_cache_dir() {
+        for cached in cached_templates(&cache_root) {
+            templates.retain(|template| template.name != cached.name);
+            templates.push(cached);
+        }
+    }
+    
+    Ok(templates)
 }
 
 async fn get_available_plugins() -> Result<Vec<PluginInfo>> {
@@ -2002,4 +3992,1048 @@ async fn get_available_plugins() -> Result<Vec<PluginInfo>> {
             dependencies: vec!["prisma".to_string()],
         },
     ])
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    // The output mode is process-wide, so tests that change it take turns
+    static OUTPUT_MODE_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+    #[tokio::test]
+    async fn test_init_from_cached_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_root = temp_dir.path().join("cache");
+        let source = temp_dir.path().join("company-starter");
+        fs::create_dir_all(source.join("src")).unwrap();
+        fs::write(source.join("template.json"), r#"{ "description": "Company starter" }"#).unwrap();
+        fs::write(source.join("src/index.ts"), "console.log('{{projectName}} from cache');\n").unwrap();
+
+        let name = add_cached_template(&cache_root, &source).await.unwrap();
+        assert_eq!(name, "company-starter");
+        let templates = cached_templates(&cache_root);
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].description, "Company starter");
+
+        let project_path = temp_dir.path().join("my-app");
+        assert!(scaffold_from_cached_template(&cache_root, "company-starter", &project_path, "my-app").await.unwrap());
+        assert_eq!(
+            fs::read_to_string(project_path.join("src/index.ts")).unwrap(),
+            "console.log('my-app from cache');\n"
+        );
+        assert!(!project_path.join("template.json").exists());
+        assert!(project_path.join(".gitignore").exists());
+        assert!(project_path.join(".editorconfig").exists());
+        assert!(!scaffold_from_cached_template(&cache_root, "default", &project_path, "my-app").await.unwrap());
+
+        // Dotfiles shipped by the template are kept as they are
+        fs::write(source.join(".gitignore"), "company/\n").unwrap();
+        add_cached_template(&cache_root, &source).await.unwrap();
+        let project_path = temp_dir.path().join("other-app");
+        scaffold_from_cached_template(&cache_root, "company-starter", &project_path, "other-app").await.unwrap();
+        assert_eq!(fs::read_to_string(project_path.join(".gitignore")).unwrap(), "company/\n");
+        assert!(project_path.join(".editorconfig").exists());
+
+        remove_cached_template(&cache_root, "company-starter").await.unwrap();
+        assert!(cached_templates(&cache_root).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cached_template_names_cannot_escape_cache() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache_root = temp_dir.path().join("cache");
+        let victim = temp_dir.path().join("victim");
+        fs::create_dir_all(&cache_root).unwrap();
+        fs::create_dir_all(&victim).unwrap();
+
+        assert!(remove_cached_template(&cache_root, "../victim").await.is_err());
+        assert!(victim.exists());
+
+        let project_path = temp_dir.path().join("my-app");
+        assert!(scaffold_from_cached_template(&cache_root, "../victim", &project_path, "my-app").await.is_err());
+
+        // A symlink inside the cache must not lead outside it either
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(&victim, cache_root.join("linked")).unwrap();
+            assert!(remove_cached_template(&cache_root, "linked").await.is_err());
+            assert!(victim.exists());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deploy_rollback_restores_previous_deploy() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        let dist = project_path.join("dist");
+        let target = project_path.join("public_html");
+        fs::create_dir_all(&dist).unwrap();
+
+        assert!(rollback_deployment(project_path, None).await.is_err());
+
+        fs::write(dist.join("index.html"), "v1").unwrap();
+        let first = deploy_static(project_path, &dist, &target, false).await.unwrap();
+        assert!(rollback_deployment(project_path, None).await.is_err());
+
+        fs::write(dist.join("index.html"), "v2").unwrap();
+        fs::write(dist.join("extra.js"), "console.log('v2');").unwrap();
+        deploy_static(project_path, &dist, &target, false).await.unwrap();
+        assert_eq!(fs::read_to_string(target.join("index.html")).unwrap(), "v2");
+
+        let restored = rollback_deployment(project_path, None).await.unwrap();
+        assert_eq!(restored.id, first.id);
+        assert_eq!(fs::read_to_string(target.join("index.html")).unwrap(), "v1");
+        assert!(!target.join("extra.js").exists());
+
+        let history = load_deployment_history(project_path).unwrap();
+        assert_eq!(history.deployments.len(), 2);
+        assert_eq!(history.current.get(&first.target), Some(&first.id));
+    }
+
+    #[tokio::test]
+    async fn test_deploy_rollback_is_scoped_to_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        let dist = project_path.join("dist");
+        let staging = project_path.join("staging");
+        let production = project_path.join("production");
+        fs::create_dir_all(&dist).unwrap();
+
+        fs::write(dist.join("index.html"), "staging v1").unwrap();
+        let staging_v1 = deploy_static(project_path, &dist, &staging, false).await.unwrap();
+        fs::write(dist.join("index.html"), "production v1").unwrap();
+        let production_v1 = deploy_static(project_path, &dist, &production, false).await.unwrap();
+        fs::write(dist.join("index.html"), "staging v2").unwrap();
+        deploy_static(project_path, &dist, &staging, false).await.unwrap();
+
+        // With two targets live the target must be named
+        assert!(rollback_deployment(project_path, None).await.is_err());
+        assert!(rollback_deployment(project_path, Some(Path::new("production"))).await.is_err());
+
+        let restored = rollback_deployment(project_path, Some(Path::new("staging"))).await.unwrap();
+        assert_eq!(restored.id, staging_v1.id);
+        assert_eq!(fs::read_to_string(staging.join("index.html")).unwrap(), "staging v1");
+        assert_eq!(fs::read_to_string(production.join("index.html")).unwrap(), "production v1");
+
+        let history = load_deployment_history(project_path).unwrap();
+        assert_eq!(history.current.get(&production_v1.target), Some(&production_v1.id));
+        assert_eq!(history.current.get(&staging_v1.target), Some(&staging_v1.id));
+    }
+
+    #[tokio::test]
+    async fn test_deploy_rejects_targets_covering_the_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path().join("my-app");
+        let dist = project_path.join("dist");
+        fs::create_dir_all(project_path.join("src")).unwrap();
+        fs::create_dir_all(&dist).unwrap();
+        fs::write(project_path.join("src/index.ts"), "export {};").unwrap();
+        fs::write(dist.join("index.html"), "v1").unwrap();
+
+        for target in [".", "..", "src/..", ".synapse", ".synapse/deployments/site"] {
+            assert!(deploy_static(&project_path, &dist, Path::new(target), true).await.is_err(), "{} was accepted", target);
+        }
+        assert!(project_path.join("src/index.ts").exists());
+        assert!(dist.join("index.html").exists());
+    }
+
+    #[tokio::test]
+    async fn test_deploy_refuses_to_replace_unmanaged_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        let dist = project_path.join("dist");
+        fs::create_dir_all(project_path.join("src")).unwrap();
+        fs::create_dir_all(&dist).unwrap();
+        fs::write(project_path.join("src/index.ts"), "export {};").unwrap();
+        fs::write(dist.join("index.html"), "v1").unwrap();
+
+        assert!(deploy_static(project_path, &dist, Path::new("src"), false).await.is_err());
+        assert_eq!(fs::read_to_string(project_path.join("src/index.ts")).unwrap(), "export {};");
+        assert!(!project_path.join("src/index.html").exists());
+        assert!(load_deployment_history(project_path).unwrap().deployments.is_empty());
+
+        // An empty directory is fine, and once deployed to it can be replaced again
+        fs::create_dir_all(project_path.join("public")).unwrap();
+        deploy_static(project_path, &dist, Path::new("public"), false).await.unwrap();
+        deploy_static(project_path, &dist, Path::new("public"), false).await.unwrap();
+
+        // --force replaces it anyway
+        deploy_static(project_path, &dist, Path::new("src"), true).await.unwrap();
+        assert!(project_path.join("src/index.html").exists());
+    }
+
+    #[tokio::test]
+    async fn test_deploy_rejects_file_targets_even_with_force() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        let dist = project_path.join("dist");
+        fs::create_dir_all(&dist).unwrap();
+        fs::write(dist.join("index.html"), "v1").unwrap();
+        fs::write(project_path.join("site"), "not a directory").unwrap();
+
+        assert!(deploy_static(project_path, &dist, Path::new("site"), true).await.is_err());
+        assert_eq!(fs::read_to_string(project_path.join("site")).unwrap(), "not a directory");
+        assert!(!project_path.join(".site.synapse-previous").exists());
+        assert!(load_deployment_history(project_path).unwrap().deployments.is_empty());
+    }
+
+    // Serve one completion and hand back the raw request the CLI sent
+    async fn spawn_ai_backend(completion: &'static str) -> (AiBackend, tokio::task::JoinHandle<String>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 4096];
+            // Read until the JSON body has been received
+            while !String::from_utf8_lossy(&request).ends_with('}') {
+                let read = socket.read(&mut buffer).await.unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            let body = serde_json::json!({ "completion": completion }).to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let backend = AiBackend {
+            endpoint: format!("http://{}/v1/complete", address),
+            api_key: Some("test-key".to_string()),
+            timeout: std::time::Duration::from_secs(5),
+        };
+        (backend, server)
+    }
+
+    #[tokio::test]
+    async fn test_ai_generate_uses_http_backend() {
+        let (backend, server) = spawn_ai_backend("export const greet = () => 'hi';").await;
+        let code = ai_respond(Some(&backend), "generate", "a greeting helper", String::new()).await.unwrap();
+        assert_eq!(code, "export const greet = () => 'hi';");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /v1/complete"));
+        assert!(request.contains("Bearer test-key"));
+        assert!(request.contains(r#""prompt":"a greeting helper""#));
+
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = save_generated_code(temp_dir.path(), "greet.ts", &code).await.unwrap();
+        assert_eq!(fs::read_to_string(file_path).unwrap(), code);
+    }
+
+    #[tokio::test]
+    async fn test_ai_fix_uses_http_backend() {
+        let (backend, server) = spawn_ai_backend("if (items) { items.length; }").await;
+        handle_ai_fix(Some(&backend), Some("items is undefined")).await.unwrap();
+
+        let request = server.await.unwrap();
+        assert!(request.contains(r#""action":"fix""#));
+        assert!(request.contains(r#""prompt":"items is undefined""#));
+    }
+
+    #[tokio::test]
+    async fn test_ai_backend_times_out() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        // Accept the connection but never answer
+        let _server = tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+        });
+
+        let backend = AiBackend {
+            endpoint: format!("http://{}/v1/complete", address),
+            api_key: None,
+            timeout: std::time::Duration::from_millis(200),
+        };
+        let started = std::time::Instant::now();
+        assert!(backend.complete("generate", "anything").await.is_err());
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_profile_optimize_reports_large_files_and_sync_fs() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::create_dir_all(project_path.join("src")).unwrap();
+        fs::write(project_path.join("src/big.ts"), "export const x = 1;\n".repeat(4000)).unwrap();
+        fs::write(
+            project_path.join("src/config.ts"),
+            "import fs from 'fs';\nexport const config = fs.readFileSync('config.json', 'utf8');\n",
+        ).unwrap();
+        fs::write(project_path.join("package.json"), r#"{ "dependencies": { "@snps/core": "^0.1.0" } }"#).unwrap();
+
+        let report = analyze_project_for_optimization(project_path).unwrap();
+
+        assert_eq!(report.largest_files[0].path, "src/big.ts");
+        assert_eq!(report.dependency_count, 1);
+        assert!(report.suggestions.iter().any(|s| s.starts_with("Split src/big.ts")));
+        assert!(report.suggestions.iter().any(|s| s.starts_with("src/config.ts:2 uses readFileSync")));
+    }
+
+    #[tokio::test]
+    async fn test_profile_optimize_json_prints_only_the_report() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+
+        let _mode = OUTPUT_MODE_LOCK.lock().await;
+        logger::set_mode(logger::OutputMode::Json);
+        logger::start_capture();
+        let result = handle_profile_command("optimize", temp_dir.path().to_str(), true).await;
+        let lines = logger::take_captured();
+        logger::set_mode(logger::OutputMode::Text);
+        result.unwrap();
+
+        // Status events stay off stdout, which carries only the report
+        assert!(!lines.is_empty());
+        for (stream, line) in &lines {
+            assert_eq!(*stream, logger::Stream::Stderr);
+            assert!(serde_json::from_str::<serde_json::Value>(line).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_snapshot_detects_same_tick_edits() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        let source = project_path.join("src/index.ts");
+        fs::create_dir_all(project_path.join("src")).unwrap();
+        fs::write(&source, "export {};").unwrap();
+        let previous = snapshot_source_files(project_path);
+
+        // Rewrite the file but keep its modification time, as a save within
+        // one timestamp tick would
+        let modified = fs::metadata(&source).unwrap().modified().unwrap();
+        fs::write(&source, "export const a = 1;").unwrap();
+        fs::File::options().write(true).open(&source).unwrap().set_modified(modified).unwrap();
+
+        let current = snapshot_source_files(project_path);
+        assert_eq!(changed_source_files(project_path, &previous, &current), vec!["src/index.ts".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_watch_reruns_on_source_change() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path().to_path_buf();
+        let source = project_path.join("src/index.ts");
+        fs::create_dir_all(source.parent().unwrap()).unwrap();
+        fs::write(&source, "export const a = 1;").unwrap();
+
+        let writer_source = source.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            fs::write(writer_source, "export const a = 2;\n").unwrap();
+        });
+
+        let invocations = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = invocations.clone();
+        let mut seen_changes = Vec::new();
+        let watch = watch_sources(&project_path, std::time::Duration::from_millis(50), |changed| {
+            let count = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            seen_changes.push(changed);
+            async move { Ok(count < 2) }
+        });
+        tokio::time::timeout(std::time::Duration::from_secs(5), watch).await.unwrap().unwrap();
+
+        assert_eq!(invocations.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(seen_changes[1], vec!["src/index.ts".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_hot_reload_broadcasts_source_changes() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path().to_path_buf();
+        let source = project_path.join("src/index.ts");
+        fs::create_dir_all(source.parent().unwrap()).unwrap();
+        fs::write(&source, "export const a = 1;").unwrap();
+
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let server = tokio::spawn(serve_hot_reload(project_path.clone(), port, std::time::Duration::from_millis(50)));
+
+        let mut socket = loop {
+            match tokio::net::TcpStream::connect(("127.0.0.1", port)).await {
+                Ok(socket) => break socket,
+                Err(_) => tokio::time::sleep(std::time::Duration::from_millis(20)).await,
+            }
+        };
+        socket.write_all(b"GET /events HTTP/1.1\r\n\r\n").await.unwrap();
+
+        // Read from the event stream until `needle` shows up
+        async fn read_until(socket: &mut tokio::net::TcpStream, needle: &str) -> String {
+            let mut received = String::new();
+            let mut buffer = [0u8; 1024];
+            while !received.contains(needle) {
+                let read = socket.read(&mut buffer).await.unwrap();
+                assert!(read > 0, "connection closed");
+                received.push_str(&String::from_utf8_lossy(&buffer[..read]));
+            }
+            received
+        }
+
+        let timeout = std::time::Duration::from_secs(5);
+        tokio::time::timeout(timeout, read_until(&mut socket, "text/event-stream")).await.unwrap();
+
+        fs::write(&source, "export const a = 2;\n").unwrap();
+        let received = tokio::time::timeout(timeout, read_until(&mut socket, "src/index.ts")).await.unwrap();
+        assert!(received.contains("event: reload"));
+
+        server.abort();
+    }
+
+    #[cfg(unix)]
+    fn fake_hot_reload_server() -> Command {
+        let mut command = Command::new("sh");
+        command.args(["-c", &format!("echo {}; sleep 30", HOT_RELOAD_READY)]);
+        command
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hot_reload_pid_file_lifecycle() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        let timeout = std::time::Duration::from_secs(5);
+
+        let pid = start_hot_reload_service(project_path, &mut fake_hot_reload_server(), timeout).unwrap();
+
+        let process = read_hot_reload_process(project_path).unwrap().unwrap();
+        assert_eq!(process.pid, pid);
+        assert_eq!(fs::read_to_string(hot_reload_pid_path(project_path)).unwrap(), pid.to_string());
+        assert!(owns_hot_reload_process(&process));
+        assert!(start_hot_reload_service(project_path, &mut fake_hot_reload_server(), timeout).is_err());
+
+        assert!(stop_hot_reload_service(project_path).unwrap());
+        assert!(!hot_reload_pid_path(project_path).exists());
+        assert!(!hot_reload_process_path(project_path).exists());
+        assert!(!stop_hot_reload_service(project_path).unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hot_reload_start_waits_for_ready() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+
+        // Exits without ever reporting ready
+        let mut command = Command::new("sh");
+        command.args(["-c", "exit 1"]);
+        assert!(start_hot_reload_service(project_path, &mut command, std::time::Duration::from_secs(5)).is_err());
+        assert!(!hot_reload_pid_path(project_path).exists());
+
+        // Runs but never reports ready
+        let mut command = Command::new("sleep");
+        command.arg("30");
+        assert!(start_hot_reload_service(project_path, &mut command, std::time::Duration::from_millis(200)).is_err());
+        assert!(!hot_reload_pid_path(project_path).exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hot_reload_stop_ignores_foreign_process() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+
+        // A live process that is not the one recorded in the PID file, as after PID reuse
+        let mut foreign = Command::new("sleep").arg("30").spawn().unwrap();
+        let process = HotReloadProcess {
+            pid: foreign.id(),
+            command: "synapse hot-reload serve 35729".to_string(),
+        };
+        write_hot_reload_process(project_path, &process).unwrap();
+
+        assert!(!stop_hot_reload_service(project_path).unwrap());
+        assert!(!hot_reload_pid_path(project_path).exists());
+        assert!(!hot_reload_process_path(project_path).exists());
+        assert!(foreign.try_wait().unwrap().is_none());
+
+        foreign.kill().unwrap();
+        foreign.wait().unwrap();
+    }
+
+    #[test]
+    fn test_hot_reload_port_is_optional() {
+        assert_eq!(parse_hot_reload_port(None).unwrap(), DEFAULT_HOT_RELOAD_PORT);
+        assert_eq!(parse_hot_reload_port(Some("4000")).unwrap(), 4000);
+        assert!(parse_hot_reload_port(Some("now")).is_err());
+    }
+
+    const NODE_COVERAGE_OUTPUT: &str = "\
+# tests 3
+# pass 3
+# fail 0
+# start of coverage report
+# --------------------------------------------------------------
+# file              | line % | branch % | funcs % | uncovered lines
+# --------------------------------------------------------------
+# src/math.js       | 100.00 |   100.00 |  100.00 |
+# src/strings.js    |  71.43 |    50.00 |   66.67 | 5-6
+# --------------------------------------------------------------
+# all files         |  85.71 |    75.00 |   83.33 |
+# --------------------------------------------------------------
+# end of coverage report
+";
+
+    #[test]
+    fn test_parse_coverage_summary_and_threshold() {
+        let summary = parse_coverage_summary(NODE_COVERAGE_OUTPUT).unwrap();
+
+        assert_eq!(summary.files.len(), 2);
+        assert_eq!(summary.files[1].file, "src/strings.js");
+        assert_eq!(summary.files[1].branches, 50.0);
+        assert_eq!(summary.total.lines, 85.71);
+
+        assert!(summary.meets_threshold(TestingSettings::default().coverage_threshold));
+        assert!(!summary.meets_threshold(90.0));
+        assert!(parse_coverage_summary("# tests 3\n# pass 3\n").is_none());
+    }
+
+    #[test]
+    fn test_coverage_threshold_is_read_from_testing_config() {
+        let config: ProjectConfig = serde_json::from_str(r#"{
+            "name": "my-app",
+            "version": "0.1.0",
+            "template": null,
+            "features": [],
+            "created_at": "2024-01-01T00:00:00Z",
+            "last_modified": "2024-01-01T00:00:00Z",
+            "testing": { "coverage_threshold": 60.0 }
+        }"#).unwrap();
+        assert_eq!(config.testing.coverage_threshold, 60.0);
+
+        let config: ProjectConfig = serde_json::from_str(r#"{
+            "name": "my-app",
+            "version": "0.1.0",
+            "template": null,
+            "features": [],
+            "created_at": "2024-01-01T00:00:00Z",
+            "last_modified": "2024-01-01T00:00:00Z"
+        }"#).unwrap();
+        assert_eq!(config.testing.coverage_threshold, 80.0);
+    }
+
+    #[test]
+    fn test_coverage_runs_only_node_test_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::create_dir_all(project_path.join("tests")).unwrap();
+        // Counted by the plain test runner, but node would never run it
+        fs::write(project_path.join("tests/helpers.ts"), "export {};").unwrap();
+        assert!(run_coverage(project_path, 80.0, None, false, None).is_ok());
+
+        if Command::new("node").arg("--version").output().is_err() {
+            return;
+        }
+
+        fs::write(
+            project_path.join("tests/math.test.js"),
+            "const test = require('node:test');\nconst assert = require('node:assert');\ntest('adds', () => assert.strictEqual(1 + 1, 3));\n",
+        ).unwrap();
+        let error = run_coverage(project_path, 0.0, None, true, None).unwrap_err();
+        assert_eq!(error.to_string(), "Tests failed");
+
+        fs::write(
+            project_path.join("tests/math.test.js"),
+            "const test = require('node:test');\nconst assert = require('node:assert');\ntest('adds', () => assert.strictEqual(1 + 1, 2));\n",
+        ).unwrap();
+        assert!(run_coverage(project_path, 0.0, None, true, None).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_build_uses_config_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::create_dir_all(project_path.join("src")).unwrap();
+        fs::write(project_path.join("src/index.ts"), "export {};").unwrap();
+        fs::create_dir_all(project_path.join(".synapse")).unwrap();
+        fs::write(
+            project_path.join(".synapse/config.json"),
+            r#"{
+                "name": "my-app",
+                "version": "0.1.0",
+                "template": null,
+                "features": [],
+                "created_at": "2024-01-01T00:00:00Z",
+                "last_modified": "2024-01-01T00:00:00Z",
+                "defaults": { "build": { "minify": true, "output": "out" } }
+            }"#,
+        ).unwrap();
+
+        build_project_at(project_path, None, None, None).await.unwrap();
+
+        let build_info: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(project_path.join("out/build-info.json")).unwrap()).unwrap();
+        assert_eq!(build_info["minified"], true);
+
+        // An explicit output flag still wins over the configured default
+        build_project_at(project_path, Some("dist".to_string()), None, None).await.unwrap();
+        assert!(project_path.join("dist/build-info.json").exists());
+
+        // So does --no-minify over a configured `true`
+        build_project_at(project_path, None, Some(false), None).await.unwrap();
+        let build_info: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(project_path.join("out/build-info.json")).unwrap()).unwrap();
+        assert_eq!(build_info["minified"], false);
+    }
+
+    fn write_config_defaults(project_path: &Path, defaults: serde_json::Value) -> ProjectConfig {
+        fs::create_dir_all(project_path.join(".synapse")).unwrap();
+        let config = serde_json::json!({
+            "name": "my-app",
+            "version": "0.1.0",
+            "template": null,
+            "features": [],
+            "created_at": "2024-01-01T00:00:00Z",
+            "last_modified": "2024-01-01T00:00:00Z",
+            "defaults": defaults,
+        });
+        fs::write(project_path.join(".synapse/config.json"), config.to_string()).unwrap();
+        get_project_config(&project_path.to_path_buf()).unwrap()
+    }
+
+    #[test]
+    fn test_format_uses_config_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = write_config_defaults(temp_dir.path(), serde_json::json!({}));
+        assert!(!resolve_format_check(&config, None));
+
+        let config = write_config_defaults(temp_dir.path(), serde_json::json!({ "format": { "check": true } }));
+        assert!(resolve_format_check(&config, None));
+        // --no-check still wins over a configured `true`
+        assert!(!resolve_format_check(&config, Some(false)));
+    }
+
+    #[test]
+    fn test_test_uses_config_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = write_config_defaults(temp_dir.path(), serde_json::json!({}));
+        assert_eq!(resolve_test_flags(&config, None, None), (false, false));
+
+        let config = write_config_defaults(
+            temp_dir.path(),
+            serde_json::json!({ "test": { "watch": true, "coverage": true } }),
+        );
+        assert_eq!(resolve_test_flags(&config, None, None), (true, true));
+        // Explicit flags still win over the configured defaults
+        assert_eq!(resolve_test_flags(&config, Some(false), None), (false, true));
+        assert_eq!(resolve_test_flags(&config, None, Some(false)), (true, false));
+    }
+
+    #[test]
+    fn test_generate_prefers_custom_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        let template_dir = project_path.join(".synapse/templates/component");
+        fs::create_dir_all(&template_dir).unwrap();
+        fs::write(
+            template_dir.join("{{Name}}.tsx"),
+            "// {{NAME}}\nexport const {{name}} = <{{Name}} />;\n",
+        ).unwrap();
+
+        let files = render_generator(project_path, "component", "user-profile").unwrap();
+
+        assert_eq!(files, vec![project_path.join("src/components/UserProfile.tsx")]);
+        let contents = fs::read_to_string(&files[0]).unwrap();
+        assert_eq!(contents, "// USER_PROFILE\nexport const userProfile = <UserProfile />;\n");
+    }
+
+    #[test]
+    fn test_generate_falls_back_to_builtin_template() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let files = render_generator(temp_dir.path(), "hook", "windowSize").unwrap();
+
+        assert_eq!(files, vec![temp_dir.path().join("src/hooks/useWindowSize.ts")]);
+        assert!(render_generator(temp_dir.path(), "unknown", "thing").is_err());
+    }
+
+    #[test]
+    fn test_generate_writes_nothing_when_any_output_exists() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        let template_dir = project_path.join(".synapse/templates/component");
+        fs::create_dir_all(&template_dir).unwrap();
+        fs::write(template_dir.join("{{Name}}.tsx"), "export const {{Name}} = null;\n").unwrap();
+        fs::write(template_dir.join("{{Name}}.test.tsx"), "// {{Name}} tests\n").unwrap();
+
+        // Only the file generated last already exists
+        fs::create_dir_all(project_path.join("src/components")).unwrap();
+        fs::write(project_path.join("src/components/Card.tsx"), "// mine").unwrap();
+
+        assert!(render_generator(project_path, "component", "card").is_err());
+        assert!(!project_path.join("src/components/Card.test.tsx").exists());
+        assert_eq!(fs::read_to_string(project_path.join("src/components/Card.tsx")).unwrap(), "// mine");
+    }
+
+    #[tokio::test]
+    async fn test_plugin_install_updates_package_json_and_state() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::write(
+            project_path.join("package.json"),
+            r#"{ "name": "my-app", "version": "0.1.0", "scripts": {}, "dependencies": { "@snps/core": "^0.1.0" } }"#,
+        ).unwrap();
+
+        install_plugin(project_path, "@snps/auth").await.unwrap();
+
+        let package_json = read_package_json(project_path).unwrap();
+        assert_eq!(package_json["dependencies"]["@snps/auth"], "^1.0.0");
+        assert_eq!(package_json["dependencies"]["@snps/core"], "^0.1.0");
+        let keys: Vec<&String> = package_json.as_object().unwrap().keys().collect();
+        assert_eq!(keys, ["name", "version", "scripts", "dependencies"]);
+
+        let installed = load_installed_plugins(project_path).unwrap();
+        assert_eq!(installed.len(), 1);
+        assert!(installed[0].installed);
+
+        let plugins = list_plugins(project_path).await.unwrap();
+        assert!(plugins.iter().any(|p| p.name == "@snps/auth" && p.installed));
+        assert!(plugins.iter().any(|p| p.name == "@snps/ui" && !p.installed));
+
+        remove_plugin(project_path, "@snps/auth").await.unwrap();
+        let package_json = read_package_json(project_path).unwrap();
+        assert!(package_json["dependencies"].get("@snps/auth").is_none());
+        assert!(load_installed_plugins(project_path).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_plugin_list_reconciles_recorded_state() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::write(
+            project_path.join("package.json"),
+            r#"{ "dependencies": { "@acme/legacy": "^2.0.0" } }"#,
+        ).unwrap();
+        install_plugin(project_path, "@snps/auth").await.unwrap();
+
+        // Recorded but no longer offered by the registry
+        let mut installed = load_installed_plugins(project_path).unwrap();
+        installed.push(PluginInfo {
+            name: "@acme/legacy".to_string(),
+            version: "2.0.0".to_string(),
+            description: "Retired plugin".to_string(),
+            installed: true,
+            dependencies: vec![],
+        });
+        save_installed_plugins(project_path, &installed).unwrap();
+
+        // Removed from package.json by hand
+        let mut package_json = read_package_json(project_path).unwrap();
+        package_json["dependencies"].as_object_mut().unwrap().remove("@snps/auth");
+        write_package_json(project_path, &package_json).unwrap();
+
+        let plugins = list_plugins(project_path).await.unwrap();
+        assert!(plugins.iter().any(|p| p.name == "@acme/legacy" && p.installed && p.description == "Retired plugin"));
+        assert!(plugins.iter().any(|p| p.name == "@snps/auth" && !p.installed));
+
+        let installed = load_installed_plugins(project_path).unwrap();
+        assert_eq!(installed.len(), 1);
+        assert_eq!(installed[0].name, "@acme/legacy");
+    }
+
+    #[tokio::test]
+    async fn test_plugin_list_keeps_state_when_package_json_is_malformed() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::write(project_path.join("package.json"), "{}").unwrap();
+        install_plugin(project_path, "@snps/auth").await.unwrap();
+        let state = fs::read_to_string(plugin_state_path(project_path)).unwrap();
+
+        fs::write(project_path.join("package.json"), r#"{ "dependencies": { "@snps/auth": "^1.0.0", } "#).unwrap();
+        assert!(list_plugins(project_path).await.is_err());
+        assert_eq!(fs::read_to_string(plugin_state_path(project_path)).unwrap(), state);
+
+        fs::remove_file(project_path.join("package.json")).unwrap();
+        assert!(list_plugins(project_path).await.is_err());
+        assert_eq!(fs::read_to_string(plugin_state_path(project_path)).unwrap(), state);
+    }
+
+    #[tokio::test]
+    async fn test_plugin_install_rejects_unknown_name() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("package.json"), "{}").unwrap();
+
+        assert!(install_plugin(temp_dir.path(), "left-pad").await.is_err());
+    }
+
+    #[test]
+    fn test_dev_certificate_is_generated_and_reused() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let cert = ensure_dev_certificate(temp_dir.path()).unwrap();
+        let cert_pem = fs::read_to_string(&cert.cert_path).unwrap();
+        let key_pem = fs::read_to_string(&cert.key_path).unwrap();
+        assert!(cert.cert_path.starts_with(temp_dir.path().join(".synapse")));
+        assert!(key_pem.contains("PRIVATE KEY"));
+
+        let (_, pem) = x509_parser::pem::parse_x509_pem(cert_pem.as_bytes()).unwrap();
+        let parsed = pem.parse_x509().unwrap();
+        let san = parsed.subject_alternative_name().unwrap().unwrap();
+        use x509_parser::extensions::GeneralName;
+        assert!(san.value.general_names.iter().any(|name| matches!(name, GeneralName::DNSName("localhost"))));
+        assert!(san.value.general_names.iter().any(|name| matches!(name, GeneralName::IPAddress(ip) if *ip == [127, 0, 0, 1])));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&cert.key_path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let reused = ensure_dev_certificate(temp_dir.path()).unwrap();
+        assert_eq!(fs::read_to_string(&reused.cert_path).unwrap(), cert_pem);
+    }
+
+    #[tokio::test]
+    async fn test_init_generates_gitignore_and_editorconfig() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path().join("my-app");
+
+        create_project_structure(&project_path, "my-app", "enterprise").await.unwrap();
+
+        let gitignore = fs::read_to_string(project_path.join(".gitignore")).unwrap();
+        for entry in ["node_modules/", "dist/", ".synapse-cache/", ".synapse/certs/", "deployments/secrets/"] {
+            assert!(gitignore.lines().any(|line| line == entry), "missing {}", entry);
+        }
+        assert!(project_path.join(".editorconfig").exists());
+
+        // The dev certificate's private key is ignored whatever the template
+        for template in ["default", "api", "fullstack", "ui-library"] {
+            assert!(generate_gitignore(template).lines().any(|line| line == ".synapse/certs/"), "{} misses certs", template);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_init_keeps_existing_gitignore() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path().join("my-app");
+        fs::create_dir_all(&project_path).unwrap();
+        fs::write(project_path.join(".gitignore"), "custom/\n").unwrap();
+
+        create_project_structure(&project_path, "my-app", "default").await.unwrap();
+
+        let gitignore = fs::read_to_string(project_path.join(".gitignore")).unwrap();
+        assert_eq!(gitignore, "custom/\n");
+    }
+
+    #[tokio::test]
+    async fn test_rust_compile_produces_output() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::create_dir_all(project_path.join("src/utils")).unwrap();
+        fs::write(project_path.join("src/index.ts"), "const answer: number = 42;").unwrap();
+        fs::write(project_path.join("src/utils/math.ts"), "export const b = 2;").unwrap();
+
+        // Defaults to compiling src/ into dist/
+        handle_rust_command_at(project_path, "compile", None).await.unwrap();
+        let output = fs::read_to_string(project_path.join("dist/index.js")).unwrap();
+        assert!(output.contains("const answer: number = 42;"));
+        assert!(project_path.join("dist/index.js.map").exists());
+        assert!(project_path.join("dist/utils/math.js").exists());
+        assert!(project_path.join("dist/utils/math.js.map").exists());
+
+        // An explicit target is resolved against the project
+        fs::remove_dir_all(project_path.join("dist")).unwrap();
+        handle_rust_command_at(project_path, "compile", Some("src/index.ts")).await.unwrap();
+        assert!(project_path.join("dist/index.js").exists());
+        assert!(project_path.join("dist/index.js.map").exists());
+        assert!(!project_path.join("dist/utils").exists());
+    }
+
+    #[tokio::test]
+    async fn test_rust_quantum_reports_an_error() {
+        let error = handle_rust_command("quantum", None).await.unwrap_err();
+        assert!(error.to_string().contains("not available"));
+    }
+
+    #[tokio::test]
+    async fn test_build_and_rust_compile_produce_identical_output() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::create_dir_all(project_path.join("src/utils")).unwrap();
+        fs::write(project_path.join("src/index.ts"), "export const answer: number = 42;").unwrap();
+        fs::write(project_path.join("src/utils/math.ts"), "export const double = (n: number): number => n * 2;").unwrap();
+
+        // `rust compile` always writes to dist/, so build into a different directory
+        build_project_at(project_path, Some("build".to_string()), Some(false), Some(false)).await.unwrap();
+        handle_rust_command_at(project_path, "compile", None).await.unwrap();
+
+        for output in ["index.js", "utils/math.js"] {
+            let built = fs::read_to_string(project_path.join("build").join(output)).unwrap();
+            let compiled = fs::read_to_string(project_path.join("dist").join(output)).unwrap();
+            assert_eq!(built, compiled, "{} differs", output);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_global_json_flag_sends_events_to_stderr() {
+        let _mode = OUTPUT_MODE_LOCK.lock().await;
+        let cli = Cli::parse_from(["synapse", "lint", "--json"]);
+        logger::set_mode(logger::OutputMode::from_flags(cli.json, cli.quiet));
+        assert_eq!(logger::mode(), logger::OutputMode::Json);
+
+        logger::start_capture();
+        logger::init("linting", "🔍 Linting System initialized");
+        let json_lines = logger::take_captured();
+
+        let cli = Cli::parse_from(["synapse", "--quiet", "lint"]);
+        logger::set_mode(logger::OutputMode::from_flags(cli.json, cli.quiet));
+        logger::start_capture();
+        logger::init("linting", "🔍 Linting System initialized");
+        let quiet_lines = logger::take_captured();
+        logger::set_mode(logger::OutputMode::Text);
+
+        assert_eq!(json_lines.len(), 1);
+        assert_eq!(json_lines[0].0, logger::Stream::Stderr);
+        let event: serde_json::Value = serde_json::from_str(&json_lines[0].1).unwrap();
+        assert_eq!(event, serde_json::json!({ "event": "init", "component": "linting" }));
+        assert!(quiet_lines.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lint_emits_json_init_event() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+        fs::write(temp_dir.path().join("src/index.ts"), "export {};").unwrap();
+
+        let _mode = OUTPUT_MODE_LOCK.lock().await;
+        logger::set_mode(logger::OutputMode::Json);
+        logger::start_capture();
+        let result = lint_code_at(temp_dir.path(), None).await;
+        let lines = logger::take_captured();
+        logger::set_mode(logger::OutputMode::Text);
+        result.unwrap();
+
+        assert_eq!(lines.len(), 1);
+        assert!(!lines[0].1.contains("Linting System initialized"));
+        let event: serde_json::Value = serde_json::from_str(&lines[0].1).unwrap();
+        assert_eq!(event, serde_json::json!({ "event": "init", "component": "linting" }));
+    }
+
+    #[tokio::test]
+    async fn test_incremental_build_recompiles_only_changed_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::create_dir_all(project_path.join("src/utils")).unwrap();
+        fs::write(project_path.join("src/index.ts"), "export const a = 1;").unwrap();
+        fs::write(project_path.join("src/utils/math.ts"), "export const b = 2;").unwrap();
+
+        let read_build_info = || -> serde_json::Value {
+            serde_json::from_str(&fs::read_to_string(project_path.join("dist/build-info.json")).unwrap()).unwrap()
+        };
+
+        build_project_at(project_path, None, None, Some(true)).await.unwrap();
+        assert_eq!(read_build_info()["files_compiled"], 2);
+        assert!(project_path.join(".synapse-cache/build-manifest.json").exists());
+
+        fs::write(project_path.join("src/utils/math.ts"), "export const b = 3;").unwrap();
+        fs::remove_dir_all(project_path.join("dist")).unwrap();
+        build_project_at(project_path, None, None, Some(true)).await.unwrap();
+
+        let build_info = read_build_info();
+        assert_eq!(build_info["files_compiled"], 1);
+        assert_eq!(build_info["files_reused"], 1);
+        assert!(fs::read_to_string(project_path.join("dist/utils/math.js")).unwrap().contains("b = 3"));
+        assert!(fs::read_to_string(project_path.join("dist/index.js")).unwrap().contains("a = 1"));
+
+        // Changing compiler settings invalidates the whole cache
+        build_project_at(project_path, None, Some(true), Some(true)).await.unwrap();
+        assert_eq!(read_build_info()["files_compiled"], 2);
+    }
+
+    #[test]
+    fn test_failed_sources_do_not_keep_stale_output() {
+        let temp_dir = TempDir::new().unwrap();
+        let cached_output_dir = temp_dir.path().join(".synapse-cache/build");
+        let output_dir = temp_dir.path().join("dist");
+        for dir in [&cached_output_dir, &output_dir] {
+            fs::create_dir_all(dir.join("utils")).unwrap();
+            fs::write(dir.join("utils/math.js"), "export const b = 2;").unwrap();
+            fs::write(dir.join("utils/math.js.map"), "{}").unwrap();
+            fs::write(dir.join("index.js"), "export const a = 1;").unwrap();
+        }
+
+        discard_stale_output(&cached_output_dir, &output_dir, Path::new("utils/math.js")).unwrap();
+
+        for dir in [&cached_output_dir, &output_dir] {
+            assert!(!dir.join("utils/math.js").exists());
+            assert!(!dir.join("utils/math.js.map").exists());
+            assert!(dir.join("index.js").exists());
+        }
+    }
+
+    #[test]
+    fn test_shards_cover_all_test_files_without_overlap() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        fs::create_dir_all(project_path.join("tests/unit")).unwrap();
+        for i in 0..20 {
+            fs::write(project_path.join(format!("tests/unit/case{}.test.ts", i)), "").unwrap();
+        }
+
+        let discovered = find_files_by_extension(&project_path.join("tests"), "ts").unwrap();
+        let first = TestShard::parse("1/2").unwrap();
+        let second = TestShard::parse("2/2").unwrap();
+
+        let mut covered: Vec<&PathBuf> = discovered
+            .iter()
+            .filter(|file| first.contains(project_path, file))
+            .chain(discovered.iter().filter(|file| second.contains(project_path, file)))
+            .collect();
+        assert_eq!(covered.len(), discovered.len());
+
+        covered.sort();
+        covered.dedup();
+        assert_eq!(covered.len(), discovered.len());
+    }
+
+    #[test]
+    fn test_coverage_shards_only_pass_node_test_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path();
+        for file in [
+            "tests/math.test.js",
+            "tests/helpers.js",
+            "tests/math.test.ts",
+            "test/setup.mjs",
+            "src/parser_test.mjs",
+            "src/test-login.cjs",
+            "src/app.js",
+            "node_modules/dep/index.test.js",
+        ] {
+            let path = project_path.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+
+        let discovered: Vec<String> = find_node_test_files(project_path)
+            .unwrap()
+            .iter()
+            .map(|file| file.strip_prefix(project_path).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect();
+        assert_eq!(
+            discovered,
+            vec!["src/parser_test.mjs", "src/test-login.cjs", "test/setup.mjs", "tests/math.test.js"]
+        );
+    }
+
+    #[test]
+    fn test_shard_parse_validates_bounds() {
+        assert_eq!(TestShard::parse("3/4").unwrap(), TestShard { index: 3, total: 4 });
+        assert!(TestShard::parse("0/2").is_err());
+        assert!(TestShard::parse("3/2").is_err());
+        assert!(TestShard::parse("0/0").is_err());
+        assert!(TestShard::parse("2").is_err());
+    }
+}
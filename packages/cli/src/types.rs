@@ -12,6 +12,57 @@ pub struct ProjectConfig {
     pub features: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub last_modified: DateTime<Utc>,
+    /// Default flag values per command, e.g. `{ "build": { "minify": true } }`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub defaults: HashMap<String, HashMap<String, serde_json::Value>>,
+    #[serde(default)]
+    pub testing: TestingSettings,
+    #[serde(default)]
+    pub ai: AiSettings,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct TestingSettings {
+    /// Minimum total line coverage, in percent, for `synapse test --coverage` to pass
+    pub coverage_threshold: f64,
+}
+
+impl Default for TestingSettings {
+    fn default() -> Self {
+        Self {
+            coverage_threshold: 80.0,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct AiSettings {
+    /// HTTP completion endpoint for `synapse ai`; canned responses are used when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+    /// Seconds to wait for the endpoint before giving up
+    pub timeout_secs: u64,
+}
+
+impl Default for AiSettings {
+    fn default() -> Self {
+        Self {
+            endpoint: None,
+            timeout_secs: 30,
+        }
+    }
+}
+
+impl ProjectConfig {
+    /// Look up a configured default for a command flag
+    pub fn command_default<T: serde::de::DeserializeOwned>(&self, command: &str, flag: &str) -> Option<T> {
+        self.defaults
+            .get(command)
+            .and_then(|flags| flags.get(flag))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -39,6 +90,46 @@ pub struct DeploymentConfig {
     pub credentials: HashMap<String, String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DeploymentRecord {
+    pub id: String,
+    pub target: String,
+    pub snapshot: String,
+    pub manifest: Vec<DeployedFile>,
+    pub success: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DeployedFile {
+    pub path: String,
+    pub hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct DeploymentHistory {
+    /// Id of the deployment currently live on each target, keyed by target path
+    #[serde(default)]
+    pub current: HashMap<String, String>,
+    pub deployments: Vec<DeploymentRecord>,
+}
+
+/// Contents of `.synapse/hot-reload.json`, written next to the bare PID in `.synapse/hot-reload.pid`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HotReloadProcess {
+    pub pid: u32,
+    /// Command line the server was spawned with, used to tell it apart from a reused PID
+    pub command: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BuildManifest {
+    /// Hash of the compiler settings the cached outputs were built with
+    pub config_hash: String,
+    /// Content hash of each source, keyed by its path relative to src/
+    pub files: HashMap<String, String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SecurityReport {
     pub vulnerabilities: Vec<Vulnerability>,
@@ -80,6 +171,12 @@ pub struct PerformanceMetrics {
 #[command(about = "🚀 The Ultimate Development CLI - 15+ powerful features")]
 #[command(long_about = None)]
 pub struct Cli {
+    /// Machine-readable output: commands print JSON reports, status events go to stderr as JSON lines
+    #[arg(long, global = true)]
+    pub json: bool,
+    /// Suppress status events
+    #[arg(short, long, global = true, conflicts_with = "json")]
+    pub quiet: bool,
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -99,49 +196,95 @@ pub enum Commands {
     },
     /// Start development server
     Dev {
-        /// Port to run on
-        #[arg(short, long, default_value = "3000")]
-        port: u16,
+        /// Port to run on [default: 3000]
+        #[arg(short, long)]
+        port: Option<u16>,
         /// Open browser automatically
-        #[arg(long)]
+        #[arg(long, overrides_with = "no_open")]
         open: bool,
+        /// Don't open the browser, even if the config enables it
+        #[arg(long, overrides_with = "open")]
+        no_open: bool,
+        /// Prepare a self-signed localhost certificate for HTTPS (TLS serving is not available yet)
+        #[arg(long, overrides_with = "no_https")]
+        https: bool,
+        /// Skip the localhost certificate, even if the config enables HTTPS
+        #[arg(long, overrides_with = "https")]
+        no_https: bool,
     },
     /// Build for production
     Build {
-        /// Output directory
-        #[arg(short, long, default_value = "dist")]
-        output: String,
+        /// Output directory [default: dist]
+        #[arg(short, long)]
+        output: Option<String>,
         /// Minify output
-        #[arg(long)]
+        #[arg(long, overrides_with = "no_minify")]
         minify: bool,
+        /// Don't minify output, even if the config enables it
+        #[arg(long, overrides_with = "minify")]
+        no_minify: bool,
+        /// Only recompile sources that changed since the last build
+        #[arg(long, overrides_with = "no_incremental")]
+        incremental: bool,
+        /// Rebuild every source, even if the config enables incremental builds
+        #[arg(long, overrides_with = "incremental")]
+        no_incremental: bool,
     },
     /// Run tests
     Test {
         /// Test pattern
         pattern: Option<String>,
         /// Watch mode
-        #[arg(short, long)]
+        #[arg(short, long, overrides_with = "no_watch")]
         watch: bool,
+        /// Run once, even if the config enables watch mode
+        #[arg(long, overrides_with = "watch")]
+        no_watch: bool,
+        /// Collect coverage and enforce the coverage threshold
+        #[arg(long, overrides_with = "no_coverage")]
+        coverage: bool,
+        /// Skip coverage, even if the config enables it
+        #[arg(long, overrides_with = "coverage")]
+        no_coverage: bool,
+        /// Run only one shard of the test files, e.g. `1/4`
+        #[arg(long, value_name = "INDEX/TOTAL")]
+        shard: Option<String>,
     },
     /// Lint code
     Lint {
         /// Fix issues automatically
-        #[arg(long)]
+        #[arg(long, overrides_with = "no_fix")]
         fix: bool,
+        /// Only report issues, even if the config enables fixing
+        #[arg(long, overrides_with = "fix")]
+        no_fix: bool,
+        /// Re-run whenever source files change
+        #[arg(short, long)]
+        watch: bool,
     },
     /// Format code
     Format {
         /// Check formatting without changing files
-        #[arg(long)]
+        #[arg(long, overrides_with = "no_check")]
         check: bool,
+        /// Rewrite files, even if the config enables checking only
+        #[arg(long, overrides_with = "check")]
+        no_check: bool,
+        /// Re-run whenever source files change
+        #[arg(short, long)]
+        watch: bool,
     },
     /// Generate code
     Generate {
         /// Type of code to generate
-        #[arg(short, long)]
-        type_: String,
+        #[arg(short, long, required_unless_present = "list")]
+        type_: Option<String>,
         /// Name of the generated code
-        name: String,
+        #[arg(required_unless_present = "list")]
+        name: Option<String>,
+        /// List available generators
+        #[arg(long)]
+        list: bool,
     },
     /// Plugin management
     Plugin {
@@ -149,6 +292,9 @@ pub enum Commands {
         action: String,
         /// Plugin name
         name: Option<String>,
+        /// Run npm install after updating package.json
+        #[arg(long)]
+        sync: bool,
     },
     /// Template management
     Template {
@@ -191,6 +337,9 @@ pub enum Commands {
         action: String,
         /// Target
         target: Option<String>,
+        /// Replace an existing target directory that synapse has not deployed to
+        #[arg(long)]
+        force: bool,
     },
     /// Monitoring
     Monitor {
@@ -5,14 +5,19 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Compiler configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CompilerConfig {
     pub minify: bool,
     pub source_maps: bool,
     pub target: String,
+    /// Base config file this one extends, relative to this file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extends: Option<PathBuf>,
 }
 
 impl Default for CompilerConfig {
@@ -21,10 +26,65 @@ impl Default for CompilerConfig {
             minify: false,
             source_maps: true,
             target: "es2022".to_string(),
+            extends: None,
         }
     }
 }
 
+impl CompilerConfig {
+    /// Load configuration from a JSON file, resolving `extends` chains.
+    /// Fields set in a file override those inherited from its base.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let value = load_config_layers(path, &mut Vec::new())?;
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+fn load_config_layers(path: &Path, chain: &mut Vec<PathBuf>) -> Result<serde_json::Value> {
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("Cannot read config {}: {}", path.display(), e))?;
+
+    if chain.contains(&canonical) {
+        let cycle: Vec<String> = chain
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|p| p.display().to_string())
+            .collect();
+        return Err(anyhow::anyhow!("Config extends cycle: {}", cycle.join(" -> ")));
+    }
+    chain.push(canonical);
+
+    let mut value: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+    if let Some(base) = value.get("extends").and_then(|extends| extends.as_str()) {
+        let base_path = path.parent().unwrap_or_else(|| Path::new(".")).join(base);
+        let mut merged = load_config_layers(&base_path, chain)?;
+        overlay_config(&mut merged, value);
+        value = merged;
+    }
+
+    chain.pop();
+    Ok(value)
+}
+
+// Recursively lay `overlay` on top of `base`, keeping base values the overlay omits
+fn overlay_config(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match (base, overlay) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => overlay_config(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
 /// Compilation result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompilationResult {
@@ -66,6 +126,16 @@ impl SynapseCompiler {
         })
     }
 
+    /// Compile several files sequentially, keyed by input path.
+    /// This crate has no quantum compiler yet, so there is no quantum result
+    /// type to convert into and nothing to benchmark this against.
+    pub fn compile_files(&self, paths: &[PathBuf]) -> Result<HashMap<PathBuf, CompilationResult>> {
+        paths
+            .iter()
+            .map(|path| Ok((path.clone(), self.compile_file(path)?)))
+            .collect()
+    }
+
     /// Get compiler version
     pub fn version() -> &'static str {
         env!("CARGO_PKG_VERSION")
@@ -93,6 +163,59 @@ mod tests {
         assert!(result.output.contains("const x: number = 42"));
     }
 
+    #[test]
+    fn test_compile_files_matches_compile_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths: Vec<PathBuf> = (0..3)
+            .map(|i| {
+                let path = temp_dir.path().join(format!("module{}.ts", i));
+                fs::write(&path, format!("export const value{}: number = {};", i, i)).unwrap();
+                path
+            })
+            .collect();
+
+        let compiler = SynapseCompiler::new(CompilerConfig::default()).unwrap();
+        let batch = compiler.compile_files(&paths).unwrap();
+
+        assert_eq!(batch.len(), paths.len());
+        for path in &paths {
+            let single = compiler.compile_file(path).unwrap();
+            assert!(batch[path].success);
+            assert_eq!(batch[path].output, single.output);
+        }
+    }
+
+    #[test]
+    fn test_config_extends_base() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("configs")).unwrap();
+        fs::write(
+            temp_dir.path().join("configs/base.json"),
+            r#"{ "minify": false, "source_maps": false, "target": "es2020" }"#,
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("production.json"),
+            r#"{ "extends": "configs/base.json", "minify": true }"#,
+        )
+        .unwrap();
+
+        let config = CompilerConfig::from_file(&temp_dir.path().join("production.json")).unwrap();
+        assert!(config.minify);
+        assert!(!config.source_maps);
+        assert_eq!(config.target, "es2020");
+    }
+
+    #[test]
+    fn test_config_extends_cycle_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a.json"), r#"{ "extends": "b.json" }"#).unwrap();
+        fs::write(temp_dir.path().join("b.json"), r#"{ "extends": "a.json" }"#).unwrap();
+
+        let error = CompilerConfig::from_file(&temp_dir.path().join("a.json")).unwrap_err();
+        assert!(error.to_string().contains("cycle"));
+    }
+
     #[test]
     fn test_version() {
         let version = SynapseCompiler::version();
@@ -69,7 +69,7 @@ async fn compile_file(
     let config = CompilerConfig {
         minify,
         source_maps,
-        target: "es2022".to_string(),
+        ..CompilerConfig::default()
     };
 
     let compiler = SynapseCompiler::new(config)?;